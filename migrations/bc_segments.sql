@@ -0,0 +1,9 @@
+-- Insert velocity-banded BCs published for Sierra MatchKing bullets
+INSERT OR IGNORE INTO load_bc_segments (load_id, velocity, bc) VALUES
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 175gr'), 2800.0, 0.505),
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 175gr'), 1800.0, 0.496),
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 175gr'), 0.0, 0.485),
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 168gr'), 2600.0, 0.462),
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 168gr'), 2100.0, 0.447),
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 168gr'), 1600.0, 0.424),
+    ((SELECT id FROM load_data WHERE name='Gold Medal Match 308 Win 168gr'), 0.0, 0.405);
@@ -0,0 +1,24 @@
+-- Bullet diameters (inches) for the calibers in the factory load data
+UPDATE load_data SET bullet_diameter = CASE caliber
+        WHEN '.17 HMR' THEN 0.172
+        WHEN '.22 Long Rifle' THEN 0.223
+        WHEN '.22 Winchester Magnum' THEN 0.224
+        WHEN '.223 Remington' THEN 0.224
+        WHEN '5.56x45mm NATO' THEN 0.224
+        WHEN '.243 Winchester' THEN 0.243
+        WHEN '6mm Creedmoor' THEN 0.243
+        WHEN '6.5 Creedmoor' THEN 0.264
+        WHEN '6.5 PRC' THEN 0.264
+        WHEN '.270 Winchester' THEN 0.277
+        WHEN '7mm Remington Magnum' THEN 0.284
+        WHEN '.308 Winchester' THEN 0.308
+        WHEN '.30-06 Springfield' THEN 0.308
+        WHEN '.300 Winchester Magnum' THEN 0.308
+        WHEN '.338 Lapua Magnum' THEN 0.338
+        WHEN '9mm Luger' THEN 0.355
+        WHEN '.380 ACP' THEN 0.355
+        WHEN '.38 Special' THEN 0.357
+        WHEN '.40 S&W' THEN 0.400
+        WHEN '.45 ACP' THEN 0.451
+    END
+    WHERE bullet_diameter IS NULL;
@@ -13,17 +13,33 @@ CREATE TABLE IF NOT EXISTS load_data (
     bullet_weight REAL NOT NULL,
     velocity REAL NOT NULL,
     bc REAL NOT NULL,
+    drag_model TEXT NOT NULL DEFAULT 'G1', -- 'G1', 'G7', ... reference projectile for bc
     powder_type TEXT,
     powder_charge REAL,
+    temp_sensitivity REAL, -- fps per degree F of powder temperature, NULL if unknown
+    reference_temperature REAL, -- degrees F at which velocity was measured
+    test_barrel_length REAL, -- inches, barrel velocity was measured in
+    bullet_diameter REAL, -- inches
     category TEXT NOT NULL DEFAULT 'Rifle', -- 'Rifle', 'Pistol', 'Rimfire'
     FOREIGN KEY (manufacturer_id) REFERENCES manufacturers(id),
     UNIQUE(manufacturer_id, name)
 );
 
+-- Create velocity-banded BC table; each row's bc applies at or above velocity
+CREATE TABLE IF NOT EXISTS load_bc_segments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    load_id INTEGER NOT NULL,
+    velocity REAL NOT NULL, -- fps, lower threshold of the band
+    bc REAL NOT NULL,
+    FOREIGN KEY (load_id) REFERENCES load_data(id),
+    UNIQUE(load_id, velocity)
+);
+
 -- Create indexes for better performance
 CREATE INDEX IF NOT EXISTS idx_load_data_manufacturer ON load_data(manufacturer_id);
 CREATE INDEX IF NOT EXISTS idx_load_data_caliber ON load_data(caliber);
 CREATE INDEX IF NOT EXISTS idx_load_data_category ON load_data(category);
+CREATE INDEX IF NOT EXISTS idx_load_bc_segments_load ON load_bc_segments(load_id);
 
 -- Insert manufacturers
 INSERT OR IGNORE INTO manufacturers (name) VALUES 
@@ -115,4 +131,4 @@ INSERT OR IGNORE INTO load_data (manufacturer_id, name, caliber, bullet_weight,
     ((SELECT id FROM manufacturers WHERE name='Remington'), 'HTP 38 Special +P 110gr', '.38 Special', 110.0, 995.0, 0.140, 'Unique', 5.5, 'Pistol'),
     -- Remington Rimfire
     ((SELECT id FROM manufacturers WHERE name='Remington'), 'Thunderbolt 22 LR 40gr', '.22 Long Rifle', 40.0, 1255.0, 0.138, 'Rimfire Powder', 1.7, 'Rimfire'),
-    ((SELECT id FROM manufacturers WHERE name='Remington'), 'Golden Bullet 22 LR 36gr HP', '.22 Long Rifle', 36.0, 1280.0, 0.125, 'Rimfire Powder', 1.8, 'Rimfire');
\ No newline at end of file
+    ((SELECT id FROM manufacturers WHERE name='Remington'), 'Golden Bullet 22 LR 36gr HP', '.22 Long Rifle', 36.0, 1280.0, 0.125, 'Rimfire Powder', 1.8, 'Rimfire');
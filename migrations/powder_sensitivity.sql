@@ -0,0 +1,32 @@
+-- Typical powder temperature sensitivity (fps per degree F) for the powders in
+-- the factory load data, referenced to the 70 F SAAMI test temperature.
+-- Temperature-stable extruded powders move least, ball and older IMR
+-- powders most; rimfire and primer-only loads are left unknown
+UPDATE load_data SET
+        temp_sensitivity = CASE powder_type
+            WHEN 'Varget' THEN 0.3
+            WHEN 'H4350' THEN 0.3
+            WHEN 'H4831' THEN 0.3
+            WHEN 'H4831SC' THEN 0.3
+            WHEN 'H1000' THEN 0.35
+            WHEN 'Retumbo' THEN 0.4
+            WHEN 'RL26' THEN 0.5
+            WHEN 'IMR 4064' THEN 1.0
+            WHEN 'IMR 4350' THEN 1.1
+            WHEN 'IMR 4831' THEN 1.1
+            WHEN 'RL22' THEN 1.6
+            WHEN 'Superformance' THEN 1.0
+            WHEN 'TAC' THEN 1.2
+            WHEN 'Titegroup' THEN 0.4
+            WHEN 'HP-38' THEN 0.6
+            WHEN 'Universal' THEN 0.6
+            WHEN 'Unique' THEN 1.0
+            WHEN 'Power Pistol' THEN 1.0
+            WHEN 'Longshot' THEN 1.0
+        END,
+        reference_temperature = 70.0
+    WHERE temp_sensitivity IS NULL AND powder_type IN (
+        'Varget', 'H4350', 'H4831', 'H4831SC', 'H1000', 'Retumbo', 'RL26',
+        'IMR 4064', 'IMR 4350', 'IMR 4831', 'RL22', 'Superformance', 'TAC',
+        'Titegroup', 'HP-38', 'Universal', 'Unique', 'Power Pistol', 'Longshot'
+    );
@@ -0,0 +1,10 @@
+-- Standard test barrel lengths (inches) that published factory velocities are measured in
+UPDATE load_data SET test_barrel_length = 24.0
+    WHERE test_barrel_length IS NULL AND category IN ('Rifle', 'Rimfire');
+
+UPDATE load_data SET test_barrel_length = CASE caliber
+        WHEN '.45 ACP' THEN 5.0
+        WHEN '.380 ACP' THEN 3.75
+        ELSE 4.0
+    END
+    WHERE test_barrel_length IS NULL AND category = 'Pistol';
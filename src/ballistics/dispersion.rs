@@ -0,0 +1,251 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use super::{BallisticsCalculator, BallisticsError, ProjectileData, RangeTable, RangeUnit, Vec3};
+
+const MOA_TO_RADIANS: f64 = std::f64::consts::PI / (180.0 * 60.0);
+
+/// One-sigma input errors for a Monte Carlo run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Uncertainty {
+    pub velocity_sd: f64,   // ft/s
+    pub bc_sd: f64,         // fraction of BC, e.g. 0.02 for 2%
+    pub range_sd: f64,      // yards of range estimation error
+    pub wind_speed_sd: f64, // mph
+    pub wind_angle_sd: f64, // degrees
+    pub precision_moa: f64, // rifle and shooter, per axis
+    pub samples: usize,
+    #[serde(default)]
+    pub seed: Option<u64>, // fixed seed for repeatable runs
+}
+
+impl Default for Uncertainty {
+    fn default() -> Self {
+        Self {
+            velocity_sd: 10.0,
+            bc_sd: 0.02,
+            range_sd: 5.0,
+            wind_speed_sd: 2.0,
+            wind_angle_sd: 15.0,
+            precision_moa: 0.5,
+            samples: 1000,
+            seed: None,
+        }
+    }
+}
+
+/// Target centred on the point of aim (inches).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TargetShape {
+    Circle { diameter: f64 },
+    Rectangle { width: f64, height: f64 },
+}
+
+impl Default for TargetShape {
+    fn default() -> Self {
+        TargetShape::Circle { diameter: 10.0 }
+    }
+}
+
+impl TargetShape {
+    pub fn contains(&self, horizontal: f64, vertical: f64) -> bool {
+        match *self {
+            TargetShape::Circle { diameter } => horizontal.hypot(vertical) <= diameter / 2.0,
+            TargetShape::Rectangle { width, height } => {
+                horizontal.abs() <= width / 2.0 && vertical.abs() <= height / 2.0
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for TargetShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetShape::Circle { diameter } => write!(f, "{}\" circle", diameter),
+            TargetShape::Rectangle { width, height } => {
+                write!(f, "{}\" x {}\" rectangle", width, height)
+            }
+        }
+    }
+}
+
+/// Spread of impacts about the point of aim at one range.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DispersionPoint {
+    pub distance: f64,        // in the result's range unit
+    pub vertical_mean: f64,   // inches, positive = high
+    pub vertical_sd: f64,     // inches
+    pub horizontal_mean: f64, // inches, positive = right
+    pub horizontal_sd: f64,   // inches
+    pub hit_probability: f64, // 0-1
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DispersionResult {
+    pub points: Vec<DispersionPoint>,
+    pub range_unit: RangeUnit,
+    pub target: TargetShape,
+    pub samples: usize,
+}
+
+impl BallisticsCalculator {
+    /// Monte Carlo dispersion: fly `uncertainty.samples` shots with randomized
+    /// inputs, each aimed with the nominal solution for the range table
+    /// distance, and measure where they land on a target at the true range.
+    pub fn dispersion(
+        &self,
+        data: &ProjectileData,
+        uncertainty: &Uncertainty,
+        target: &TargetShape,
+    ) -> Result<DispersionResult, BallisticsError> {
+        let zero_angle = self.calculate_zero_angle(data)?;
+        let table = data.range_table.distances().map(|d| (data.range_table.unit, d));
+        let (unit, distances) = match table {
+            Ok(table) => table,
+            Err(_) => {
+                let table = RangeTable::default();
+                (table.unit, table.distances()?)
+            }
+        };
+        let mut distances: Vec<f64> = distances.into_iter().filter(|&d| d > 0.0).collect();
+        let mut ranges_feet: Vec<f64> = distances.iter().map(|&d| unit.to_feet(d)).collect();
+
+        // Nominal solution the shooter dials and holds, as angles per range;
+        // ranges the nominal shot never reaches are dropped
+        let nominal = self.solve(data, &self.flight(data), zero_angle, &ranges_feet);
+        distances.truncate(nominal.len());
+        ranges_feet.truncate(nominal.len());
+        let holds: Vec<(f64, f64)> = nominal
+            .iter()
+            .zip(ranges_feet.iter())
+            .map(|(state, &x)| (state.pos.y / x, state.pos.z / x))
+            .collect();
+
+        let mut rng = match uncertainty.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let samples = uncertainty.samples.max(1);
+        let mut impacts: Vec<Vec<(f64, f64)>> = vec![Vec::with_capacity(samples); holds.len()];
+
+        for _ in 0..samples {
+            let mut shot = data.clone();
+            shot.velocity += normal(&mut rng) * uncertainty.velocity_sd;
+            let wind_speed_error = normal(&mut rng) * uncertainty.wind_speed_sd;
+            let wind_angle_error = normal(&mut rng) * uncertainty.wind_angle_sd;
+            shot.wind_speed = (shot.wind_speed + wind_speed_error).max(0.0);
+            shot.wind_angle += wind_angle_error;
+            for zone in &mut shot.wind_zones {
+                zone.speed = (zone.speed + wind_speed_error).max(0.0);
+                zone.angle += wind_angle_error;
+            }
+
+            // Scale the whole drag model so segments and measured curves vary too
+            let bc_scale = (1.0 + normal(&mut rng) * uncertainty.bc_sd).max(0.1);
+            let mut flight = self.flight(&shot);
+            flight.bc *= bc_scale;
+            for segment in &mut flight.bc_segments {
+                segment.bc *= bc_scale;
+            }
+
+            let precision = uncertainty.precision_moa * MOA_TO_RADIANS;
+            let elevation_error = normal(&mut rng) * precision;
+            let windage_error = normal(&mut rng) * precision;
+
+            // True ranges, kept in order so a single flight covers them all
+            let range_error_sd = uncertainty.range_sd * 3.0;
+            let mut true_ranges: Vec<(usize, f64)> = ranges_feet
+                .iter()
+                .map(|&x| (x + normal(&mut rng) * range_error_sd).max(1.0))
+                .enumerate()
+                .collect();
+            true_ranges.sort_by(|a, b| a.1.total_cmp(&b.1));
+            let sorted_feet: Vec<f64> = true_ranges.iter().map(|&(_, x)| x).collect();
+
+            let states = self.solve(&shot, &flight, zero_angle + elevation_error, &sorted_feet);
+            for (&(i, x), state) in true_ranges.iter().zip(states.iter()) {
+                let (hold_up, hold_right) = holds[i];
+                let offset = Vec3::new(0.0, hold_up * x, hold_right * x - windage_error * x);
+                let impact = state.pos - offset;
+                impacts[i].push((impact.z * 12.0, impact.y * 12.0));
+            }
+        }
+
+        let points = distances
+            .iter()
+            .zip(impacts.iter())
+            .map(|(&distance, shots)| {
+                let n = shots.len().max(1) as f64;
+                let (horizontal_mean, vertical_mean) = shots
+                    .iter()
+                    .fold((0.0, 0.0), |(h, v), &(x, y)| (h + x / n, v + y / n));
+                let horizontal_sd = (shots
+                    .iter()
+                    .map(|&(x, _)| (x - horizontal_mean).powi(2))
+                    .sum::<f64>()
+                    / n)
+                    .sqrt();
+                let vertical_sd = (shots
+                    .iter()
+                    .map(|&(_, y)| (y - vertical_mean).powi(2))
+                    .sum::<f64>()
+                    / n)
+                    .sqrt();
+                // Shots that never reach the target count as misses
+                let hits = shots.iter().filter(|&&(x, y)| target.contains(x, y)).count();
+
+                DispersionPoint {
+                    distance,
+                    vertical_mean,
+                    vertical_sd,
+                    horizontal_mean,
+                    horizontal_sd,
+                    hit_probability: hits as f64 / samples as f64,
+                }
+            })
+            .collect();
+
+        Ok(DispersionResult {
+            points,
+            range_unit: unit,
+            target: *target,
+            samples,
+        })
+    }
+}
+
+/// Standard normal sample by the Box-Muller transform.
+fn normal<R: Rng>(rng: &mut R) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_contains_points_inside_its_outline() {
+        let circle = TargetShape::Circle { diameter: 10.0 };
+        assert!(circle.contains(3.0, 4.0));
+        assert!(!circle.contains(3.0, 4.1));
+
+        let rectangle = TargetShape::Rectangle { width: 6.0, height: 10.0 };
+        assert!(rectangle.contains(-3.0, 5.0));
+        assert!(!rectangle.contains(3.1, 0.0));
+        assert!(!rectangle.contains(0.0, -5.1));
+    }
+
+    #[test]
+    fn normal_samples_have_unit_spread() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let samples: Vec<f64> = (0..20_000).map(|_| normal(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let sd = (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64).sqrt();
+        assert!(mean.abs() < 0.03, "mean {:.3}", mean);
+        assert!((sd - 1.0).abs() < 0.03, "sd {:.3}", sd);
+    }
+}
@@ -0,0 +1,562 @@
+#![allow(clippy::approx_constant)]
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::BallisticsError;
+
+/// Standard reference projectile a ballistic coefficient is quoted against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DragModel {
+    #[default]
+    G1,
+    G2,
+    G5,
+    G6,
+    G7,
+    G8,
+    GI,
+    GS,
+    RA4,
+}
+
+impl DragModel {
+    pub const ALL: [DragModel; 9] = [
+        DragModel::G1,
+        DragModel::G2,
+        DragModel::G5,
+        DragModel::G6,
+        DragModel::G7,
+        DragModel::G8,
+        DragModel::GI,
+        DragModel::GS,
+        DragModel::RA4,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DragModel::G1 => "G1",
+            DragModel::G2 => "G2",
+            DragModel::G5 => "G5",
+            DragModel::G6 => "G6",
+            DragModel::G7 => "G7",
+            DragModel::G8 => "G8",
+            DragModel::GI => "GI",
+            DragModel::GS => "GS",
+            DragModel::RA4 => "RA4",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            DragModel::G1 => "Flat base, 2 caliber blunt nose",
+            DragModel::G2 => "Aberdeen J projectile",
+            DragModel::G5 => "Short 7.5° boat tail, 6.19 caliber tangent ogive",
+            DragModel::G6 => "Flat base, 6 caliber secant ogive",
+            DragModel::G7 => "Long 7.5° boat tail, 10 caliber tangent ogive",
+            DragModel::G8 => "Flat base, 10 caliber secant ogive",
+            DragModel::GI => "Ingalls",
+            DragModel::GS => "Sphere",
+            DragModel::RA4 => "Rimfire .22 LR",
+        }
+    }
+
+    /// Reference drag coefficient versus Mach number, ascending in Mach.
+    pub fn table(&self) -> &'static [(f64, f64)] {
+        match self {
+            DragModel::G1 => G1_TABLE,
+            DragModel::G2 => G2_TABLE,
+            DragModel::G5 => G5_TABLE,
+            DragModel::G6 => G6_TABLE,
+            DragModel::G7 => G7_TABLE,
+            DragModel::G8 => G8_TABLE,
+            DragModel::GI => GI_TABLE,
+            DragModel::GS => GS_TABLE,
+            DragModel::RA4 => RA4_TABLE,
+        }
+    }
+
+    /// Drag coefficient at `mach`, linearly interpolated and held constant
+    /// beyond either end of the table.
+    pub fn drag_coefficient(&self, mach: f64) -> f64 {
+        interpolate_cd(self.table(), mach)
+    }
+}
+
+impl fmt::Display for DragModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DragModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DragModel::ALL
+            .iter()
+            .copied()
+            .find(|m| m.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("Unknown drag model: {}", s))
+    }
+}
+
+/// Measured drag coefficient curve for a specific bullet, typically from
+/// Doppler radar. Used in place of a BC and reference table: the Cd values are
+/// the bullet's own, so the form factor is 1 and drag scales with sectional
+/// density.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DragCurve {
+    #[serde(default)]
+    pub id: String, // assigned on import when missing
+    #[serde(default)]
+    pub name: String, // defaults to the imported file's name
+    pub diameter: f64,           // inches, reference diameter the Cd was measured against
+    pub points: Vec<(f64, f64)>, // (Mach, Cd), ascending in Mach
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Accepted JSON layouts for an imported curve.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DragCurveJson {
+    Curve(DragCurve),
+    Pairs(Vec<(f64, f64)>),
+    Objects(Vec<MachCd>),
+}
+
+#[derive(Deserialize)]
+struct MachCd {
+    #[serde(alias = "Mach", alias = "M")]
+    mach: f64,
+    #[serde(alias = "Cd", alias = "CD")]
+    cd: f64,
+}
+
+impl DragCurve {
+    pub fn new(name: &str, diameter: f64, points: Vec<(f64, f64)>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.to_string(),
+            diameter,
+            points,
+            notes: String::new(),
+        }
+    }
+
+    /// Parse Mach/Cd pairs from CSV. Blank lines, `#` comments and a
+    /// non-numeric header row are skipped; fields may be separated by commas,
+    /// semicolons, tabs or spaces. A `# diameter: 0.308` comment sets the
+    /// reference diameter; without one it is left at 0 for the caller to ask
+    /// for, as `calculate` cannot use the curve until it is set.
+    pub fn from_csv(name: &str, text: &str) -> Result<Self, BallisticsError> {
+        let mut diameter = 0.0;
+        let mut points = Vec::new();
+
+        for (line_no, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                let comment = comment.trim().to_ascii_lowercase();
+                if let Some(value) = comment.strip_prefix("diameter") {
+                    let value = value.trim_start_matches(|c: char| c == ':' || c == '=' || c.is_whitespace());
+                    diameter = value.parse().map_err(|_| {
+                        BallisticsError::DragCurveParse(format!("invalid diameter on line {}", line_no + 1))
+                    })?;
+                }
+                continue;
+            }
+
+            let fields: Vec<&str> = line
+                .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+                .filter(|f| !f.is_empty())
+                .collect();
+
+            let parsed = match fields.as_slice() {
+                [mach, cd, ..] => mach.parse::<f64>().ok().zip(cd.parse::<f64>().ok()),
+                _ => None,
+            };
+
+            match parsed {
+                Some(point) => points.push(point),
+                // Header rows are only allowed before the data starts
+                None if points.is_empty() => continue,
+                None => {
+                    return Err(BallisticsError::DragCurveParse(format!(
+                        "expected Mach and Cd on line {}",
+                        line_no + 1
+                    )))
+                }
+            }
+        }
+
+        let curve = Self::new(name, diameter, points);
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Parse a curve from JSON: either a curve object (only `diameter` and
+    /// `points` required), an array of `[mach, cd]` pairs or an array of
+    /// `{"mach": .., "cd": ..}` objects. The arrays carry no diameter, which is
+    /// left at 0 as for CSV.
+    pub fn from_json(name: &str, text: &str) -> Result<Self, BallisticsError> {
+        let parsed: DragCurveJson =
+            serde_json::from_str(text).map_err(|e| BallisticsError::DragCurveParse(e.to_string()))?;
+
+        let curve = match parsed {
+            DragCurveJson::Curve(mut curve) => {
+                if curve.id.is_empty() {
+                    curve.id = uuid::Uuid::new_v4().to_string();
+                }
+                if curve.name.is_empty() {
+                    curve.name = name.to_string();
+                }
+                curve
+            }
+            DragCurveJson::Pairs(points) => Self::new(name, 0.0, points),
+            DragCurveJson::Objects(points) => {
+                Self::new(name, 0.0, points.into_iter().map(|p| (p.mach, p.cd)).collect())
+            }
+        };
+
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Check the curve has enough points, strictly increasing Mach values and
+    /// physically sensible Cd values. The diameter is checked separately by
+    /// the calculator since CSV files usually don't carry it.
+    pub fn validate(&self) -> Result<(), BallisticsError> {
+        if self.points.len() < 2 {
+            return Err(BallisticsError::DragCurveTooShort);
+        }
+
+        for (row, &(mach, cd)) in self.points.iter().enumerate() {
+            if !mach.is_finite() || mach < 0.0 {
+                return Err(BallisticsError::DragCurveNotMonotonic { row, mach });
+            }
+            if !cd.is_finite() || cd <= 0.0 {
+                return Err(BallisticsError::DragCurveInvalidCd { mach, cd });
+            }
+            if row > 0 && mach <= self.points[row - 1].0 {
+                return Err(BallisticsError::DragCurveNotMonotonic { row, mach });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn mach_range(&self) -> (f64, f64) {
+        match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => (0.0, 0.0),
+        }
+    }
+
+    /// Check the curve spans the Mach numbers a shot will pass through.
+    pub fn check_coverage(&self, min_mach: f64, max_mach: f64) -> Result<(), BallisticsError> {
+        let (lo, hi) = self.mach_range();
+        // Allow a little slack at the ends, where the curve is held constant
+        if min_mach < lo - 0.05 || max_mach > hi + 0.05 {
+            return Err(BallisticsError::DragCurveCoverage {
+                min: lo,
+                max: hi,
+                needed_min: min_mach,
+                needed_max: max_mach,
+            });
+        }
+        Ok(())
+    }
+
+    /// Drag coefficient at `mach`, interpolated exactly like the standard
+    /// tables.
+    pub fn drag_coefficient(&self, mach: f64) -> f64 {
+        interpolate_cd(&self.points, mach)
+    }
+
+    /// Sectional density (lb/in²) of a bullet of `mass` grains with this
+    /// curve's reference diameter; plays the role of the BC for a measured
+    /// curve.
+    pub fn sectional_density(&self, mass: f64) -> f64 {
+        if self.diameter > 0.0 {
+            mass / 7000.0 / (self.diameter * self.diameter)
+        } else {
+            0.0
+        }
+    }
+}
+
+pub(crate) fn interpolate_cd(table: &[(f64, f64)], mach: f64) -> f64 {
+    if table.is_empty() {
+        return 0.0;
+    }
+    if mach <= table[0].0 {
+        return table[0].1;
+    }
+
+    let i = table.partition_point(|&(m, _)| m < mach);
+    if i >= table.len() {
+        return table[table.len() - 1].1;
+    }
+
+    let (m0, cd0) = table[i - 1];
+    let (m1, cd1) = table[i];
+    cd0 + (cd1 - cd0) * (mach - m0) / (m1 - m0)
+}
+
+// Reference Cd tables. A few entries happen to resemble std constants, hence
+// the module-level `approx_constant` allow.
+#[rustfmt::skip]
+const G1_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.2629), (0.05, 0.2558), (0.10, 0.2487), (0.15, 0.2413), (0.20, 0.2344),
+    (0.25, 0.2278), (0.30, 0.2214), (0.35, 0.2155), (0.40, 0.2104), (0.45, 0.2061),
+    (0.50, 0.2032), (0.55, 0.2020), (0.60, 0.2034), (0.70, 0.2165), (0.725, 0.2230),
+    (0.75, 0.2313), (0.775, 0.2417), (0.80, 0.2546), (0.825, 0.2706), (0.85, 0.2901),
+    (0.875, 0.3136), (0.90, 0.3415), (0.925, 0.3734), (0.95, 0.4084), (0.975, 0.4448),
+    (1.00, 0.4805), (1.025, 0.5136), (1.05, 0.5427), (1.075, 0.5677), (1.10, 0.5883),
+    (1.125, 0.6053), (1.15, 0.6191), (1.20, 0.6393), (1.25, 0.6518), (1.30, 0.6589),
+    (1.35, 0.6621), (1.40, 0.6625), (1.45, 0.6607), (1.50, 0.6573), (1.55, 0.6528),
+    (1.60, 0.6474), (1.65, 0.6413), (1.70, 0.6347), (1.75, 0.6280), (1.80, 0.6210),
+    (1.85, 0.6141), (1.90, 0.6072), (1.95, 0.6003), (2.00, 0.5934), (2.05, 0.5867),
+    (2.10, 0.5804), (2.15, 0.5743), (2.20, 0.5685), (2.25, 0.5630), (2.30, 0.5577),
+    (2.35, 0.5527), (2.40, 0.5481), (2.45, 0.5438), (2.50, 0.5397), (2.60, 0.5325),
+    (2.70, 0.5264), (2.80, 0.5211), (2.90, 0.5168), (3.00, 0.5133), (3.10, 0.5105),
+    (3.20, 0.5084), (3.30, 0.5067), (3.40, 0.5054), (3.50, 0.5040), (3.60, 0.5030),
+    (3.70, 0.5022), (3.80, 0.5016), (3.90, 0.5010), (4.00, 0.5006), (4.20, 0.4998),
+    (4.40, 0.4995), (4.60, 0.4992), (4.80, 0.4990), (5.00, 0.4988),
+];
+
+#[rustfmt::skip]
+const G2_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.2303), (0.05, 0.2298), (0.10, 0.2287), (0.15, 0.2271), (0.20, 0.2251),
+    (0.25, 0.2227), (0.30, 0.2196), (0.35, 0.2156), (0.40, 0.2107), (0.45, 0.2048),
+    (0.50, 0.1980), (0.55, 0.1905), (0.60, 0.1828), (0.65, 0.1758), (0.70, 0.1702),
+    (0.75, 0.1669), (0.775, 0.1664), (0.80, 0.1667), (0.825, 0.1682), (0.85, 0.1711),
+    (0.875, 0.1761), (0.90, 0.1831), (0.925, 0.2004), (0.95, 0.2589), (0.975, 0.3492),
+    (1.00, 0.3983), (1.025, 0.4075), (1.05, 0.4103), (1.075, 0.4114), (1.10, 0.4106),
+    (1.125, 0.4089), (1.15, 0.4068), (1.175, 0.4046), (1.20, 0.4021), (1.25, 0.3966),
+    (1.30, 0.3904), (1.35, 0.3835), (1.40, 0.3759), (1.45, 0.3678), (1.50, 0.3594),
+    (1.55, 0.3512), (1.60, 0.3432), (1.65, 0.3356), (1.70, 0.3282), (1.75, 0.3213),
+    (1.80, 0.3149), (1.85, 0.3089), (1.90, 0.3033), (1.95, 0.2982), (2.00, 0.2933),
+    (2.05, 0.2889), (2.10, 0.2846), (2.15, 0.2806), (2.20, 0.2768), (2.25, 0.2731),
+    (2.30, 0.2696), (2.35, 0.2663), (2.40, 0.2632), (2.45, 0.2602), (2.50, 0.2572),
+    (2.55, 0.2543), (2.60, 0.2515), (2.65, 0.2487), (2.70, 0.2460), (2.75, 0.2433),
+    (2.80, 0.2408), (2.85, 0.2382), (2.90, 0.2357), (2.95, 0.2333), (3.00, 0.2309),
+    (3.10, 0.2262), (3.20, 0.2217), (3.30, 0.2173), (3.40, 0.2132), (3.50, 0.2091),
+    (3.60, 0.2052), (3.70, 0.2014), (3.80, 0.1978), (3.90, 0.1944), (4.00, 0.1912),
+    (4.20, 0.1851), (4.40, 0.1794), (4.60, 0.1741), (4.80, 0.1693), (5.00, 0.1648),
+];
+
+#[rustfmt::skip]
+const G5_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.1710), (0.05, 0.1719), (0.10, 0.1727), (0.15, 0.1732), (0.20, 0.1734),
+    (0.25, 0.1730), (0.30, 0.1718), (0.35, 0.1696), (0.40, 0.1668), (0.45, 0.1637),
+    (0.50, 0.1603), (0.55, 0.1566), (0.60, 0.1529), (0.65, 0.1497), (0.70, 0.1473),
+    (0.75, 0.1463), (0.80, 0.1489), (0.85, 0.1583), (0.875, 0.1672), (0.90, 0.1815),
+    (0.925, 0.2051), (0.95, 0.2413), (0.975, 0.2884), (1.00, 0.3379), (1.025, 0.3785),
+    (1.05, 0.4032), (1.075, 0.4147), (1.10, 0.4201), (1.15, 0.4278), (1.20, 0.4338),
+    (1.25, 0.4373), (1.30, 0.4392), (1.35, 0.4403), (1.40, 0.4406), (1.45, 0.4401),
+    (1.50, 0.4386), (1.55, 0.4362), (1.60, 0.4328), (1.65, 0.4286), (1.70, 0.4237),
+    (1.75, 0.4182), (1.80, 0.4121), (1.85, 0.4057), (1.90, 0.3991), (1.95, 0.3926),
+    (2.00, 0.3861), (2.05, 0.3800), (2.10, 0.3741), (2.15, 0.3684), (2.20, 0.3630),
+    (2.25, 0.3578), (2.30, 0.3529), (2.35, 0.3481), (2.40, 0.3435), (2.45, 0.3391),
+    (2.50, 0.3349), (2.60, 0.3269), (2.70, 0.3194), (2.80, 0.3125), (2.90, 0.3060),
+    (3.00, 0.2999), (3.10, 0.2942), (3.20, 0.2889), (3.30, 0.2838), (3.40, 0.2790),
+    (3.50, 0.2745), (3.60, 0.2703), (3.70, 0.2662), (3.80, 0.2624), (3.90, 0.2588),
+    (4.00, 0.2553), (4.20, 0.2488), (4.40, 0.2429), (4.60, 0.2376), (4.80, 0.2326),
+    (5.00, 0.2280),
+];
+
+#[rustfmt::skip]
+const G6_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.2617), (0.05, 0.2553), (0.10, 0.2491), (0.15, 0.2432), (0.20, 0.2376),
+    (0.25, 0.2324), (0.30, 0.2278), (0.35, 0.2238), (0.40, 0.2205), (0.45, 0.2177),
+    (0.50, 0.2155), (0.55, 0.2138), (0.60, 0.2126), (0.65, 0.2121), (0.70, 0.2122),
+    (0.75, 0.2132), (0.80, 0.2154), (0.85, 0.2194), (0.875, 0.2229), (0.90, 0.2297),
+    (0.925, 0.2449), (0.95, 0.2732), (0.975, 0.3141), (1.00, 0.3597), (1.025, 0.3994),
+    (1.05, 0.4261), (1.075, 0.4402), (1.10, 0.4465), (1.125, 0.4490), (1.15, 0.4497),
+    (1.175, 0.4494), (1.20, 0.4482), (1.225, 0.4464), (1.25, 0.4441), (1.30, 0.4390),
+    (1.35, 0.4336), (1.40, 0.4279), (1.45, 0.4221), (1.50, 0.4162), (1.55, 0.4102),
+    (1.60, 0.4042), (1.65, 0.3981), (1.70, 0.3919), (1.75, 0.3855), (1.80, 0.3788),
+    (1.85, 0.3721), (1.90, 0.3652), (1.95, 0.3583), (2.00, 0.3515), (2.05, 0.3447),
+    (2.10, 0.3381), (2.15, 0.3314), (2.20, 0.3249), (2.25, 0.3185), (2.30, 0.3122),
+    (2.35, 0.3060), (2.40, 0.3000), (2.45, 0.2941), (2.50, 0.2883), (2.60, 0.2772),
+    (2.70, 0.2668), (2.80, 0.2574), (2.90, 0.2487), (3.00, 0.2407), (3.10, 0.2333),
+    (3.20, 0.2265), (3.30, 0.2202), (3.40, 0.2144), (3.50, 0.2089), (3.60, 0.2039),
+    (3.70, 0.1991), (3.80, 0.1947), (3.90, 0.1905), (4.00, 0.1866), (4.20, 0.1794),
+    (4.40, 0.1730), (4.60, 0.1673), (4.80, 0.1621), (5.00, 0.1574),
+];
+
+#[rustfmt::skip]
+const G7_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.1198), (0.05, 0.1197), (0.10, 0.1196), (0.15, 0.1194), (0.20, 0.1193),
+    (0.25, 0.1194), (0.30, 0.1194), (0.35, 0.1194), (0.40, 0.1193), (0.45, 0.1193),
+    (0.50, 0.1194), (0.55, 0.1193), (0.60, 0.1194), (0.65, 0.1197), (0.70, 0.1202),
+    (0.725, 0.1207), (0.75, 0.1215), (0.775, 0.1226), (0.80, 0.1242), (0.825, 0.1266),
+    (0.85, 0.1306), (0.875, 0.1368), (0.90, 0.1464), (0.925, 0.1660), (0.95, 0.2054),
+    (0.975, 0.2993), (1.00, 0.3803), (1.025, 0.4015), (1.05, 0.4043), (1.075, 0.4034),
+    (1.10, 0.4014), (1.125, 0.3987), (1.15, 0.3955), (1.20, 0.3884), (1.25, 0.3810),
+    (1.30, 0.3732), (1.35, 0.3657), (1.40, 0.3580), (1.50, 0.3440), (1.55, 0.3376),
+    (1.60, 0.3315), (1.65, 0.3260), (1.70, 0.3209), (1.75, 0.3160), (1.80, 0.3117),
+    (1.85, 0.3078), (1.90, 0.3042), (1.95, 0.3010), (2.00, 0.2980), (2.05, 0.2951),
+    (2.10, 0.2922), (2.15, 0.2892), (2.20, 0.2864), (2.25, 0.2835), (2.30, 0.2807),
+    (2.35, 0.2779), (2.40, 0.2752), (2.45, 0.2725), (2.50, 0.2697), (2.55, 0.2670),
+    (2.60, 0.2643), (2.65, 0.2615), (2.70, 0.2588), (2.75, 0.2561), (2.80, 0.2533),
+    (2.85, 0.2506), (2.90, 0.2479), (2.95, 0.2451), (3.00, 0.2424), (3.10, 0.2368),
+    (3.20, 0.2313), (3.30, 0.2258), (3.40, 0.2205), (3.50, 0.2154), (3.60, 0.2106),
+    (3.70, 0.2060), (3.80, 0.2017), (3.90, 0.1975), (4.00, 0.1935), (4.20, 0.1861),
+    (4.40, 0.1793), (4.60, 0.1730), (4.80, 0.1672), (5.00, 0.1618),
+];
+
+#[rustfmt::skip]
+const G8_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.2105), (0.05, 0.2105), (0.10, 0.2104), (0.15, 0.2104), (0.20, 0.2103),
+    (0.25, 0.2103), (0.30, 0.2103), (0.35, 0.2103), (0.40, 0.2103), (0.45, 0.2102),
+    (0.50, 0.2102), (0.55, 0.2102), (0.60, 0.2102), (0.65, 0.2102), (0.70, 0.2103),
+    (0.75, 0.2103), (0.80, 0.2104), (0.825, 0.2104), (0.85, 0.2105), (0.875, 0.2106),
+    (0.90, 0.2109), (0.925, 0.2183), (0.95, 0.2571), (0.975, 0.3358), (1.00, 0.4068),
+    (1.025, 0.4378), (1.05, 0.4476), (1.075, 0.4493), (1.10, 0.4477), (1.125, 0.4450),
+    (1.15, 0.4419), (1.20, 0.4353), (1.25, 0.4283), (1.30, 0.4208), (1.35, 0.4133),
+    (1.40, 0.4059), (1.45, 0.3986), (1.50, 0.3915), (1.55, 0.3845), (1.60, 0.3777),
+    (1.65, 0.3710), (1.70, 0.3645), (1.75, 0.3581), (1.80, 0.3519), (1.85, 0.3458),
+    (1.90, 0.3400), (1.95, 0.3343), (2.00, 0.3288), (2.05, 0.3234), (2.10, 0.3182),
+    (2.15, 0.3131), (2.20, 0.3081), (2.25, 0.3032), (2.30, 0.2983), (2.35, 0.2937),
+    (2.40, 0.2891), (2.45, 0.2845), (2.50, 0.2802), (2.60, 0.2720), (2.70, 0.2642),
+    (2.80, 0.2569), (2.90, 0.2499), (3.00, 0.2432), (3.10, 0.2368), (3.20, 0.2308),
+    (3.30, 0.2251), (3.40, 0.2197), (3.50, 0.2147), (3.60, 0.2101), (3.70, 0.2058),
+    (3.80, 0.2019), (3.90, 0.1983), (4.00, 0.1950), (4.20, 0.1890), (4.40, 0.1837),
+    (4.60, 0.1791), (4.80, 0.1750), (5.00, 0.1713),
+];
+
+#[rustfmt::skip]
+const GI_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.2282), (0.05, 0.2282), (0.10, 0.2282), (0.15, 0.2282), (0.20, 0.2282),
+    (0.25, 0.2282), (0.30, 0.2282), (0.35, 0.2282), (0.40, 0.2282), (0.45, 0.2282),
+    (0.50, 0.2282), (0.55, 0.2282), (0.60, 0.2282), (0.65, 0.2282), (0.70, 0.2282),
+    (0.725, 0.2353), (0.75, 0.2434), (0.775, 0.2515), (0.80, 0.2596), (0.825, 0.2677),
+    (0.85, 0.2759), (0.875, 0.2913), (0.90, 0.3104), (0.925, 0.3398), (0.95, 0.3762),
+    (0.975, 0.4090), (1.00, 0.4343), (1.025, 0.4530), (1.05, 0.4669), (1.075, 0.4772),
+    (1.10, 0.4848), (1.125, 0.4906), (1.15, 0.4950), (1.175, 0.4982), (1.20, 0.5006),
+    (1.25, 0.5036), (1.30, 0.5050), (1.35, 0.5055), (1.40, 0.5054), (1.45, 0.5049),
+    (1.50, 0.5041), (1.55, 0.5031), (1.60, 0.5019), (1.70, 0.4992), (1.80, 0.4963),
+    (1.90, 0.4932), (2.00, 0.4901), (2.20, 0.4838), (2.40, 0.4775), (2.60, 0.4713),
+    (2.80, 0.4652), (3.00, 0.4593), (3.50, 0.4451), (4.00, 0.4320), (4.50, 0.4200),
+    (5.00, 0.4090),
+];
+
+#[rustfmt::skip]
+const GS_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.4662), (0.05, 0.4689), (0.10, 0.4717), (0.15, 0.4745), (0.20, 0.4772),
+    (0.25, 0.4800), (0.30, 0.4827), (0.35, 0.4852), (0.40, 0.4882), (0.45, 0.4920),
+    (0.50, 0.4970), (0.55, 0.5080), (0.60, 0.5260), (0.65, 0.5590), (0.70, 0.5920),
+    (0.75, 0.6258), (0.80, 0.6610), (0.85, 0.6985), (0.90, 0.7370), (0.95, 0.7757),
+    (1.00, 0.8140), (1.05, 0.8512), (1.10, 0.8870), (1.15, 0.9210), (1.20, 0.9510),
+    (1.25, 0.9740), (1.30, 0.9910), (1.35, 0.9990), (1.40, 1.0030), (1.45, 1.0060),
+    (1.50, 1.0080), (1.55, 1.0090), (1.60, 1.0090), (1.65, 1.0090), (1.70, 1.0090),
+    (1.75, 1.0080), (1.80, 1.0070), (1.85, 1.0060), (1.90, 1.0040), (1.95, 1.0025),
+    (2.00, 1.0010), (2.05, 0.9990), (2.10, 0.9970), (2.15, 0.9956), (2.20, 0.9940),
+    (2.25, 0.9916), (2.30, 0.9890), (2.35, 0.9869), (2.40, 0.9850), (2.45, 0.9830),
+    (2.50, 0.9810), (2.55, 0.9790), (2.60, 0.9770), (2.65, 0.9750), (2.70, 0.9730),
+    (2.75, 0.9710), (2.80, 0.9690), (2.85, 0.9670), (2.90, 0.9650), (2.95, 0.9630),
+    (3.00, 0.9610), (3.05, 0.9589), (3.10, 0.9570), (3.15, 0.9555), (3.20, 0.9540),
+    (3.25, 0.9520), (3.30, 0.9500), (3.35, 0.9485), (3.40, 0.9470), (3.45, 0.9450),
+    (3.50, 0.9430), (3.55, 0.9414), (3.60, 0.9400), (3.65, 0.9385), (3.70, 0.9370),
+    (3.75, 0.9355), (3.80, 0.9340), (3.85, 0.9325), (3.90, 0.9310), (3.95, 0.9295),
+    (4.00, 0.9280),
+];
+
+#[rustfmt::skip]
+const RA4_TABLE: &[(f64, f64)] = &[
+    (0.00, 0.2300), (0.05, 0.2300), (0.10, 0.2300), (0.15, 0.2300), (0.20, 0.2300),
+    (0.25, 0.2300), (0.30, 0.2300), (0.35, 0.2300), (0.40, 0.2300), (0.45, 0.2300),
+    (0.50, 0.2300), (0.55, 0.2300), (0.60, 0.2300), (0.65, 0.2300), (0.70, 0.2300),
+    (0.75, 0.2315), (0.80, 0.2350), (0.85, 0.2425), (0.90, 0.2580), (0.95, 0.2930),
+    (1.00, 0.3460), (1.05, 0.3850), (1.10, 0.4020), (1.20, 0.4060), (1.30, 0.4010),
+    (1.40, 0.3930), (1.50, 0.3850), (1.60, 0.3770), (1.80, 0.3620), (2.00, 0.3480),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_skips_header_and_comments_and_reads_diameter() {
+        let text = "# Doppler, 175 SMK\n# diameter: 0.308\nMach,Cd\n\n0.5, 0.23\n1.0;0.41\n2.0\t0.33\n";
+        let curve = DragCurve::from_csv("smk", text).unwrap();
+        assert_eq!(curve.name, "smk");
+        assert_eq!(curve.diameter, 0.308);
+        assert_eq!(curve.points, vec![(0.5, 0.23), (1.0, 0.41), (2.0, 0.33)]);
+        assert!(!curve.id.is_empty());
+    }
+
+    #[test]
+    fn csv_without_diameter_leaves_it_unset() {
+        let curve = DragCurve::from_csv("x", "0.5,0.2\n1.0,0.4\n").unwrap();
+        assert_eq!(curve.diameter, 0.0);
+    }
+
+    #[test]
+    fn csv_rejects_text_after_the_data_starts() {
+        let result = DragCurve::from_csv("x", "0.5,0.2\nbad row\n1.0,0.4\n");
+        assert!(matches!(result, Err(BallisticsError::DragCurveParse(_))));
+    }
+
+    #[test]
+    fn json_accepts_each_layout() {
+        let curve = DragCurve::from_json("x", r#"{"diameter":0.308,"points":[[0.5,0.2],[1.0,0.4]]}"#).unwrap();
+        assert_eq!((curve.name.as_str(), curve.diameter), ("x", 0.308));
+        assert!(!curve.id.is_empty());
+
+        let curve = DragCurve::from_json("x", "[[0.5,0.2],[1.0,0.4]]").unwrap();
+        assert_eq!(curve.points, vec![(0.5, 0.2), (1.0, 0.4)]);
+        assert_eq!(curve.diameter, 0.0);
+
+        let curve = DragCurve::from_json("x", r#"[{"Mach":0.5,"Cd":0.2},{"mach":1.0,"cd":0.4}]"#).unwrap();
+        assert_eq!(curve.points, vec![(0.5, 0.2), (1.0, 0.4)]);
+    }
+
+    #[test]
+    fn json_rejects_malformed_input() {
+        let result = DragCurve::from_json("x", r#"{"points": "none"}"#);
+        assert!(matches!(result, Err(BallisticsError::DragCurveParse(_))));
+    }
+
+    #[test]
+    fn validate_rejects_bad_curves() {
+        let curve = |points: Vec<(f64, f64)>| DragCurve::new("x", 0.308, points);
+        assert!(matches!(
+            curve(vec![(0.5, 0.2)]).validate(),
+            Err(BallisticsError::DragCurveTooShort)
+        ));
+        assert!(matches!(
+            curve(vec![(1.0, 0.2), (0.5, 0.3)]).validate(),
+            Err(BallisticsError::DragCurveNotMonotonic { row: 1, .. })
+        ));
+        assert!(matches!(
+            curve(vec![(0.5, 0.2), (0.5, 0.3)]).validate(),
+            Err(BallisticsError::DragCurveNotMonotonic { row: 1, .. })
+        ));
+        assert!(matches!(
+            curve(vec![(0.5, 0.2), (1.0, 0.0)]).validate(),
+            Err(BallisticsError::DragCurveInvalidCd { .. })
+        ));
+        assert!(matches!(
+            curve(vec![(0.5, f64::NAN), (1.0, 0.3)]).validate(),
+            Err(BallisticsError::DragCurveInvalidCd { .. })
+        ));
+        assert!(curve(vec![(0.5, 0.2), (1.0, 0.4)]).validate().is_ok());
+    }
+
+    #[test]
+    fn drag_coefficient_interpolates_and_holds_at_the_ends() {
+        let curve = DragCurve::new("x", 0.308, vec![(0.5, 0.2), (1.0, 0.4)]);
+        assert!((curve.drag_coefficient(0.75) - 0.3).abs() < 1e-12);
+        assert_eq!(curve.drag_coefficient(0.1), 0.2);
+        assert_eq!(curve.drag_coefficient(3.0), 0.4);
+    }
+}
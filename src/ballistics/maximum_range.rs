@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+use super::{interpolate, BallisticsCalculator, BallisticsError, Flight, ProjectileData, State};
+
+const MAX_ANGLE: f64 = 75.0; // degrees, past the angle of maximum range for any small arm
+const SCAN_STEP: f64 = 1.0; // degrees between the coarse elevations flown
+const ANGLE_ITERATIONS: usize = 30;
+
+/// Furthest the projectile can travel over level ground, for surface danger
+/// zones: fired at the angle of maximum range and flown until it comes back
+/// down to muzzle height. The air thins and cools with height on the way.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaximumRange {
+    pub angle: f64,           // degrees of bore elevation above horizontal
+    pub distance: f64,        // yards, to impact
+    pub time: f64,            // seconds of flight
+    pub impact_velocity: f64, // ft/s
+    pub impact_angle: f64,    // degrees below horizontal
+    pub apex: f64,            // feet above the muzzle
+    pub drift: f64,           // yards, positive = right
+}
+
+impl BallisticsCalculator {
+    /// Angle of maximum range and the resulting impact, in the current
+    /// conditions including the wind.
+    pub fn maximum_range(&self, data: &ProjectileData) -> Result<MaximumRange, BallisticsError> {
+        if data.muzzle_velocity() <= 0.0 {
+            return Err(BallisticsError::MaximumRange(
+                "needs a positive muzzle velocity".to_string(),
+            ));
+        }
+        // Fired from the muzzle over level ground rather than along a sight line
+        let level = ProjectileData {
+            shot_angle: 0.0,
+            sight_height: 0.0,
+            ..data.clone()
+        };
+        let flight = Flight {
+            lapse: true,
+            ..self.flight(&level)
+        };
+        let distance = |degrees: f64| {
+            self.impact(&level, &flight, degrees.to_radians())
+                .map_or(0.0, |(impact, _)| impact.pos.x)
+        };
+
+        // Range rises to a single peak with elevation; scan for it, then
+        // narrow in with a golden-section search
+        let best = (1..=(MAX_ANGLE / SCAN_STEP) as usize)
+            .map(|i| i as f64 * SCAN_STEP)
+            .map(|angle| (angle, distance(angle)))
+            .fold((0.0, 0.0), |best, next| if next.1 > best.1 { next } else { best });
+        if best.1 <= 0.0 {
+            return Err(BallisticsError::MaximumRange(
+                "the bullet is spent before it comes back to the ground".to_string(),
+            ));
+        }
+        let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+        let (mut lo, mut hi) = (best.0 - SCAN_STEP, best.0 + SCAN_STEP);
+        let (mut a, mut b) = (hi - ratio * (hi - lo), lo + ratio * (hi - lo));
+        let (mut fa, mut fb) = (distance(a), distance(b));
+        // Each step keeps one probe as the other in the narrower bracket, so
+        // only one new elevation is flown
+        for _ in 0..ANGLE_ITERATIONS {
+            if fa < fb {
+                lo = a;
+                (a, fa) = (b, fb);
+                b = lo + ratio * (hi - lo);
+                fb = distance(b);
+            } else {
+                hi = b;
+                (b, fb) = (a, fa);
+                a = hi - ratio * (hi - lo);
+                fa = distance(a);
+            }
+        }
+        let angle = 0.5 * (lo + hi);
+
+        let (impact, apex) = self
+            .impact(&level, &flight, angle.to_radians())
+            .ok_or_else(|| {
+                BallisticsError::MaximumRange(
+                    "the bullet is spent before it comes back to the ground".to_string(),
+                )
+            })?;
+        Ok(MaximumRange {
+            angle,
+            distance: impact.pos.x / 3.0,
+            time: impact.time,
+            impact_velocity: impact.vel.length(),
+            impact_angle: (-impact.vel.y).atan2(impact.vel.x).to_degrees(),
+            apex,
+            drift: impact.pos.z / 3.0,
+        })
+    }
+
+    /// State where a bullet fired `elevation` radians above horizontal falls
+    /// back through muzzle height, and the highest it climbed (feet).
+    fn impact(
+        &self,
+        data: &ProjectileData,
+        flight: &Flight,
+        elevation: f64,
+    ) -> Option<(State, f64)> {
+        let mut apex = 0.0_f64;
+        let mut impact = None;
+        self.fly(data, flight, elevation, |prev, curr| {
+            apex = apex.max(curr.pos.y);
+            if curr.pos.y >= 0.0 || curr.vel.y >= 0.0 {
+                return true;
+            }
+            let (mut lo, mut hi) = (prev.pos.x, curr.pos.x);
+            for _ in 0..40 {
+                let mid = 0.5 * (lo + hi);
+                if interpolate(flight, prev, curr, mid).pos.y > 0.0 {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            impact = Some(interpolate(flight, prev, curr, 0.5 * (lo + hi)));
+            false
+        });
+        impact.map(|state| (state, apex))
+    }
+}
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use super::{interpolate, BallisticsCalculator, BallisticsError, Flight, ProjectileData, State};
+
+const ELEVATION_ITERATIONS: usize = 60;
+const MAX_ELEVATION: f64 = 0.1; // radians, far beyond any sensible point-blank zero
+
+/// Maximum point-blank range for a vital zone: the zero that keeps the bullet
+/// within ±half the zone for the longest span. Distances are in yards.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointBlankRange {
+    pub vital_zone: f64,         // inches
+    pub zero_range: f64,         // far zero to sight in at
+    pub near_zero: f64,          // where the bullet first rises through the line of sight
+    pub near_limit: f64,         // bullet rises into the zone, 0 if it starts inside it
+    pub far_limit: f64,          // bullet falls out of the bottom of the zone
+    pub max_ordinate: f64,       // inches above the line of sight, half the zone
+    pub max_ordinate_range: f64, // where the bullet peaks
+}
+
+impl BallisticsCalculator {
+    /// Bore elevation that makes the trajectory peak exactly half the vital
+    /// zone above the line of sight, flown in the current conditions, with the
+    /// limits where the bullet enters and leaves the zone.
+    pub fn point_blank_range(
+        &self,
+        data: &ProjectileData,
+        vital_zone: f64,
+    ) -> Result<PointBlankRange, BallisticsError> {
+        if vital_zone.is_nan() || vital_zone <= 0.0 || data.muzzle_velocity() <= 0.0 {
+            return Err(BallisticsError::PointBlankRange(
+                "needs a positive vital zone and muzzle velocity".to_string(),
+            ));
+        }
+        let flight = self.flight(data);
+        let half_zone = vital_zone / 2.0 / 12.0; // ft
+
+        // The apex rises steadily with elevation, so bisect on it
+        let (mut lo, mut hi) = (0.0, MAX_ELEVATION);
+        if self.apex(data, &flight, hi).1 < half_zone {
+            return Err(BallisticsError::PointBlankRange(format!(
+                "the bullet cannot rise {:.1} in above the line of sight",
+                vital_zone / 2.0
+            )));
+        }
+        for _ in 0..ELEVATION_ITERATIONS {
+            let mid = 0.5 * (lo + hi);
+            if self.apex(data, &flight, mid).1 < half_zone {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let elevation = 0.5 * (lo + hi);
+        let (apex_x, apex_y) = self.apex(data, &flight, elevation);
+
+        // Near limit, near zero, far zero and far limit: heights the bullet
+        // rises through before the apex and falls through after it
+        let mut crossings = [
+            (-half_zone, true, None),
+            (0.0, true, None),
+            (0.0, false, None),
+            (-half_zone, false, None),
+        ];
+        if -data.sight_height / 12.0 >= -half_zone {
+            crossings[0].2 = Some(0.0);
+        }
+        if data.sight_height <= 0.0 {
+            crossings[1].2 = Some(0.0);
+        }
+        self.fly(data, &flight, elevation, |prev, curr| {
+            for (level, rising, found) in crossings.iter_mut() {
+                if found.is_some() {
+                    continue;
+                }
+                let crossed = if *rising {
+                    prev.pos.y < *level && curr.pos.y >= *level
+                } else {
+                    prev.pos.y > *level && curr.pos.y <= *level
+                };
+                if crossed {
+                    *found = Some(height_crossing(&flight, prev, curr, *level));
+                }
+            }
+            crossings.iter().any(|c| c.2.is_none())
+        });
+
+        let yards = |i: usize| -> Result<f64, BallisticsError> {
+            crossings[i].2.map(|feet| feet / 3.0).ok_or_else(|| {
+                BallisticsError::PointBlankRange(
+                    "the bullet is spent before it leaves the vital zone".to_string(),
+                )
+            })
+        };
+
+        Ok(PointBlankRange {
+            vital_zone,
+            zero_range: yards(2)?,
+            near_zero: yards(1)?,
+            near_limit: yards(0)?,
+            far_limit: yards(3)?,
+            max_ordinate: apex_y * 12.0,
+            max_ordinate_range: apex_x / 3.0,
+        })
+    }
+}
+
+/// Downrange distance (feet) between `a` and `b` where the bullet is at
+/// `level` feet above the line of sight.
+fn height_crossing(flight: &Flight, a: &State, b: &State, level: f64) -> f64 {
+    let rising = b.pos.y > a.pos.y;
+    let (mut lo, mut hi) = (a.pos.x, b.pos.x);
+    for _ in 0..40 {
+        let mid = 0.5 * (lo + hi);
+        let below = interpolate(flight, a, b, mid).pos.y < level;
+        if below == rising {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    0.5 * (lo + hi)
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BallisticsCalculator, Flight, ProjectileData, GRAVITY};
+
+/// Minimum terminal performance to take a class of game cleanly.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GameClass {
+    pub name: String,
+    pub min_energy: f64,   // ft-lbs, 0 = no minimum
+    pub min_velocity: f64, // ft/s, e.g. the bullet's minimum expansion velocity; 0 = no minimum
+}
+
+impl Default for GameClass {
+    fn default() -> Self {
+        Self::preset("Deer", 1000.0, 1800.0)
+    }
+}
+
+impl GameClass {
+    fn preset(name: &str, min_energy: f64, min_velocity: f64) -> Self {
+        Self {
+            name: name.to_string(),
+            min_energy,
+            min_velocity,
+        }
+    }
+
+    /// Common rule-of-thumb minimums, smallest game first.
+    pub fn presets() -> Vec<GameClass> {
+        vec![
+            Self::preset("Varmint", 400.0, 0.0),
+            Self::preset("Deer", 1000.0, 1800.0),
+            Self::preset("Elk", 1500.0, 1800.0),
+            Self::preset("Moose / Bear", 2000.0, 1800.0),
+        ]
+    }
+
+    /// Lowest speed (ft/s) that meets both minimums for a bullet of
+    /// `mass_grains`, and the minimum that sets it.
+    pub fn min_speed(&self, mass_grains: f64) -> (f64, TerminalLimit) {
+        let mass_lb = mass_grains / 7000.0;
+        let energy_speed = if self.min_energy > 0.0 && mass_lb > 0.0 {
+            (2.0 * self.min_energy * GRAVITY / mass_lb).sqrt()
+        } else {
+            0.0
+        };
+        if energy_speed >= self.min_velocity {
+            (energy_speed, TerminalLimit::Energy)
+        } else {
+            (self.min_velocity, TerminalLimit::Velocity)
+        }
+    }
+}
+
+/// Which of a game class's minimums the bullet falls below first.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TerminalLimit {
+    Energy,
+    Velocity,
+}
+
+impl std::fmt::Display for TerminalLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TerminalLimit::Energy => "energy",
+            TerminalLimit::Velocity => "velocity",
+        })
+    }
+}
+
+/// Farthest range at which the bullet still meets a game class's minimums.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EthicalRange {
+    pub range: f64, // in the result's range unit
+    pub limit: TerminalLimit,
+}
+
+impl BallisticsCalculator {
+    /// Downrange distance (feet) where the bullet slows below `game`'s
+    /// minimums, with the minimum responsible; 0 if it leaves the muzzle below
+    /// them. `None` if there are no minimums or it never slows that far.
+    pub(super) fn ethical_range(
+        &self,
+        data: &ProjectileData,
+        flight: &Flight,
+        elevation: f64,
+        game: &GameClass,
+    ) -> Option<(f64, TerminalLimit)> {
+        let (speed, limit) = game.min_speed(data.mass);
+        if speed <= 0.0 {
+            return None;
+        }
+        if data.muzzle_velocity() < speed {
+            return Some((0.0, limit));
+        }
+        // Speed over the ground, which is what the target feels
+        self.speed_crossings(data, flight, elevation, &[speed], |state| state.vel.length())[0]
+            .map(|feet| (feet, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_speed_takes_the_stricter_minimum() {
+        // 1000 ft-lbs needs 1604 ft/s from 175 grains, below the 1800 ft/s floor
+        let (speed, limit) = GameClass::default().min_speed(175.0);
+        assert_eq!((speed, limit), (1800.0, TerminalLimit::Velocity));
+
+        // 1500 ft-lbs needs 1965 ft/s
+        let (speed, limit) = GameClass::preset("Elk", 1500.0, 1800.0).min_speed(175.0);
+        assert_eq!(limit, TerminalLimit::Energy);
+        assert!((speed - 1964.7).abs() < 0.5, "{:.1}", speed);
+    }
+
+    #[test]
+    fn no_minimums_needs_no_speed() {
+        assert_eq!(GameClass::preset("None", 0.0, 0.0).min_speed(175.0).0, 0.0);
+    }
+}
@@ -0,0 +1,1132 @@
+use super::*;
+
+// Agreement with published tables, documented on `BallisticsCalculator`
+const DROP_TOLERANCE: f64 = 0.015; // fraction, or DROP_FLOOR inches if larger
+const DROP_FLOOR: f64 = 0.5;
+const VELOCITY_TOLERANCE: f64 = 0.005;
+const ENERGY_TOLERANCE: f64 = 0.01;
+const TIME_TOLERANCE: f64 = 0.005;
+
+/// One published row: yards, drop (in), velocity (ft/s), energy (ft-lbs), time (s).
+type Row = (f64, f64, f64, f64, f64);
+
+/// 175 gr .308 at 2600 ft/s, 1.5 in sight height, 100 yard zero, ICAO
+/// standard atmosphere (59 °F, 29.92 inHg, sea level, 0% humidity), no wind.
+fn reference_shot(drag_model: DragModel, bc: f64) -> ProjectileData {
+    ProjectileData {
+        caliber: ".308 Winchester".to_string(),
+        mass: 175.0,
+        velocity: 2600.0,
+        bc,
+        drag_model,
+        zero_range: 100.0,
+        sight_height: 1.5,
+        temperature: 59.0,
+        pressure: 29.92,
+        humidity: 0.0,
+        altitude: 0.0,
+        range_table: RangeTable {
+            unit: RangeUnit::Yards,
+            spec: RangeSpec::List(vec![500.0, 1000.0]),
+        },
+        ..Default::default()
+    }
+}
+
+fn assert_matches_table(data: &ProjectileData, table: &[Row]) {
+    let result = BallisticsCalculator::default().calculate(data);
+    for &(range, drop, velocity, energy, time) in table {
+        let point = result
+            .trajectory_points
+            .iter()
+            .find(|p| p.distance == range)
+            .unwrap_or_else(|| panic!("no point at {} yd", range));
+        let close = |actual: f64, expected: f64, tolerance: f64, floor: f64| {
+            (actual - expected).abs() <= (expected.abs() * tolerance).max(floor)
+        };
+        assert!(
+            close(point.drop, drop, DROP_TOLERANCE, DROP_FLOOR),
+            "drop at {} yd: {:.1} in, published {:.1}",
+            range,
+            point.drop,
+            drop
+        );
+        assert!(
+            close(point.velocity, velocity, VELOCITY_TOLERANCE, 0.0),
+            "velocity at {} yd: {:.0} ft/s, published {:.0}",
+            range,
+            point.velocity,
+            velocity
+        );
+        assert!(
+            close(point.energy, energy, ENERGY_TOLERANCE, 0.0),
+            "energy at {} yd: {:.0} ft-lbs, published {:.0}",
+            range,
+            point.energy,
+            energy
+        );
+        assert!(
+            close(point.time, time, TIME_TOLERANCE, 0.0),
+            "time at {} yd: {:.3} s, published {:.3}",
+            range,
+            point.time,
+            time
+        );
+    }
+}
+
+// JBM Ballistics trajectory calculator (jbmballistics.com), inputs as in
+// `reference_shot`
+#[test]
+fn g1_matches_published_table() {
+    let table = [
+        (500.0, -63.0, 1794.0, 1250.0, 0.695),
+        (1000.0, -410.5, 1207.0, 566.0, 1.724),
+    ];
+    assert_matches_table(&reference_shot(DragModel::G1, 0.505), &table);
+}
+
+#[test]
+fn g7_matches_published_table() {
+    let table = [
+        (500.0, -64.3, 1762.0, 1206.0, 0.701),
+        (1000.0, -430.0, 1094.0, 465.0, 1.787),
+    ];
+    assert_matches_table(&reference_shot(DragModel::G7, 0.243), &table);
+}
+
+#[test]
+fn rk4_at_small_step_agrees_with_rk45() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let rk45 = BallisticsCalculator::default().calculate(&data);
+    let rk4 = BallisticsCalculator {
+        method: IntegrationMethod::Rk4,
+        step: 0.0001,
+        ..Default::default()
+    }
+    .calculate(&data);
+
+    for (a, b) in rk45.trajectory_points.iter().zip(&rk4.trajectory_points) {
+        assert!(
+            (a.drop - b.drop).abs() < 0.05,
+            "drop at {} yd: RK45 {:.3} in, RK4 {:.3}",
+            a.distance,
+            a.drop,
+            b.drop
+        );
+        assert!(
+            (a.velocity - b.velocity).abs() < 0.1,
+            "velocity at {} yd: RK45 {:.2} ft/s, RK4 {:.2}",
+            a.distance,
+            a.velocity,
+            b.velocity
+        );
+    }
+}
+
+#[test]
+fn zero_puts_the_bullet_on_the_line_of_sight_at_the_zero_range() {
+    let calculator = BallisticsCalculator::default();
+    for zero_range in [100.0, 300.0, 1000.0] {
+        let mut data = reference_shot(DragModel::G1, 0.505);
+        data.zero_range = zero_range;
+        data.range_table.spec = RangeSpec::List(vec![zero_range]);
+
+        // Drag makes the bullet fall further than in a vacuum
+        let angle = calculator.calculate_zero_angle(&data).unwrap();
+        let time = zero_range * 3.0 / data.velocity;
+        let vacuum = ((0.5 * GRAVITY * time * time + data.sight_height / 12.0)
+            / (zero_range * 3.0))
+            .atan();
+        assert!(angle > vacuum, "{} yd zero: {} rad, vacuum {} rad", zero_range, angle, vacuum);
+
+        let point = &calculator.calculate(&data).trajectory_points[0];
+        assert!(point.drop.abs() < 0.01, "{:.3} in off at the {} yd zero", point.drop, zero_range);
+    }
+}
+
+#[test]
+fn zero_beyond_reach_reports_no_convergence() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.velocity = 600.0;
+    data.zero_range = 3000.0;
+    assert!(matches!(
+        BallisticsCalculator::default().calculate_zero_angle(&data),
+        Err(BallisticsError::ZeroNotConverged { .. })
+    ));
+
+    data.velocity = 0.0;
+    assert!(matches!(
+        BallisticsCalculator::default().calculate_zero_angle(&data),
+        Err(BallisticsError::InvalidZero)
+    ));
+}
+
+#[test]
+fn zero_is_solved_in_the_zero_conditions() {
+    let calculator = BallisticsCalculator::default();
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.zero_range = 300.0;
+    data.range_table.spec = RangeSpec::List(vec![300.0]);
+    let conditions = ZeroConditions {
+        temperature: 0.0,
+        pressure: 30.5,
+        humidity: 0.0,
+        altitude: 0.0,
+    };
+
+    // Zeroing in the cold, dense air is the same as shooting in it
+    let mut cold = data.clone();
+    cold.temperature = conditions.temperature;
+    cold.pressure = conditions.pressure;
+    data.zero_conditions = Some(conditions);
+    let angle = calculator.calculate_zero_angle(&data).unwrap();
+    assert!((angle - calculator.calculate_zero_angle(&cold).unwrap()).abs() < 1e-6);
+
+    // Denser air at the zero takes more elevation, which then shoots high in
+    // the thinner air of the current conditions
+    let mut standard = data.clone();
+    standard.zero_conditions = None;
+    assert!(angle > calculator.calculate_zero_angle(&standard).unwrap());
+    let point = &calculator.calculate(&data).trajectory_points[0];
+    assert!(point.drop > 0.1, "{:.2} in at the 300 yd zero", point.drop);
+}
+
+#[test]
+fn arden_buck_vapor_pressure_and_humid_air() {
+    // Published saturation pressures: 611.2 Pa at 0 °C, 2339 Pa at 20 °C, 4246 Pa at 30 °C
+    for (temp_f, saturation) in [(32.0, 611.2), (68.0, 2339.0), (86.0, 4246.0)] {
+        let pa = Atmosphere::vapor_pressure(temp_f, 100.0);
+        assert!((pa / saturation - 1.0).abs() < 0.002, "{} Pa at {} °F", pa, temp_f);
+        assert!((Atmosphere::vapor_pressure(temp_f, 50.0) - pa / 2.0).abs() < 1e-9);
+    }
+
+    // Water vapour is lighter than the dry air it displaces
+    let dry = Atmosphere::new(86.0, 29.92, PressureKind::Station, 0.0, 0.0);
+    let humid = Atmosphere::new(86.0, 29.92, PressureKind::Station, 100.0, 0.0);
+    assert!(humid.density < dry.density);
+    assert!(humid.density_altitude > dry.density_altitude);
+    assert!(humid.speed_of_sound > dry.speed_of_sound);
+}
+
+#[test]
+fn barometric_pressure_is_corrected_to_station_pressure() {
+    // ICAO standard atmosphere pressures at altitude
+    for (altitude, station) in [(0.0, 29.92), (5000.0, 24.90), (10000.0, 20.58)] {
+        let pressure = Atmosphere::station_pressure(29.92, altitude);
+        assert!((pressure - station).abs() < 0.01, "{} inHg at {} ft", pressure, altitude);
+
+        let barometric = Atmosphere::new(59.0, 29.92, PressureKind::Barometric, 0.0, altitude);
+        let reading = Atmosphere::new(59.0, pressure, PressureKind::Station, 0.0, altitude);
+        assert!((barometric.density - reading.density).abs() < 1e-12);
+    }
+
+    // A station reading is taken as is, whatever the altitude
+    let station = Atmosphere::new(59.0, 24.90, PressureKind::Station, 0.0, 5000.0);
+    assert_eq!(station.station_pressure, 24.90);
+}
+
+#[test]
+fn density_altitude_matches_the_standard_atmosphere() {
+    let standard = Atmosphere::standard();
+    assert!(standard.density_altitude.abs() < 10.0, "{}", standard.density_altitude);
+    assert!((standard.density_ratio() - 1.0).abs() < 1e-3);
+    assert!((standard.speed_of_sound - 1116.4).abs() < 0.5, "{}", standard.speed_of_sound);
+
+    // ICAO temperature and pressure at altitude give that density altitude
+    for (altitude, temperature, pressure) in [(5000.0, 41.17, 24.896), (10000.0, 23.34, 20.577)] {
+        let air = Atmosphere::new(temperature, pressure, PressureKind::Station, 0.0, altitude);
+        assert!(
+            (air.density_altitude - altitude).abs() < 30.0,
+            "{:.0} ft density altitude at {} ft",
+            air.density_altitude,
+            altitude
+        );
+    }
+
+    // Roughly 120 ft of density altitude per °C above standard: 2060 ft at 90 °F
+    let hot = Atmosphere::new(90.0, 29.92, PressureKind::Station, 0.0, 0.0);
+    assert!((hot.density_altitude - 2060.0).abs() < 150.0, "{}", hot.density_altitude);
+}
+
+#[test]
+fn density_altitude_reading_round_trips() {
+    let readings = [(59.0, 29.92, 0.0), (95.0, 25.5, 40.0), (20.0, 27.0, 80.0)];
+    for (temperature, pressure, humidity) in readings {
+        let air = Atmosphere::new(temperature, pressure, PressureKind::Station, humidity, 0.0);
+        let reading =
+            Atmosphere::from_density_altitude(air.density_altitude, temperature, humidity);
+        assert!((reading.density / air.density - 1.0).abs() < 1e-9);
+        assert!((reading.station_pressure - pressure).abs() < 1e-6);
+        assert!((reading.speed_of_sound - air.speed_of_sound).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn powder_sensitivity_moves_velocity_with_temperature() {
+    let powder = PowderSensitivity {
+        fps_per_degree: 1.5,
+        reference_temperature: 59.0,
+        powder_temperature: None,
+    };
+    for temperature in [-10.0, 59.0, 100.0] {
+        let expected = 2600.0 + 1.5 * (temperature - 59.0);
+        assert!((powder.adjust(2600.0, temperature) - expected).abs() < 1e-9);
+    }
+    // A measured powder temperature wins over the air
+    let sunbaked = PowderSensitivity {
+        powder_temperature: Some(120.0),
+        ..powder.clone()
+    };
+    assert!((sunbaked.adjust(2600.0, 59.0) - 2691.5).abs() < 1e-9);
+
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.temperature = 99.0;
+    data.powder = Some(powder);
+    data.range_table.spec = RangeSpec::List(vec![0.0, 100.0]);
+    let result = BallisticsCalculator::default().calculate(&data);
+    assert!((result.trajectory_points[0].velocity - 2660.0).abs() < 1e-6);
+}
+
+#[test]
+fn zero_uses_the_velocity_at_the_zero_temperature() {
+    let calculator = BallisticsCalculator::default();
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.powder = Some(PowderSensitivity {
+        fps_per_degree: 1.5,
+        reference_temperature: 59.0,
+        powder_temperature: Some(99.0),
+    });
+    data.temperature = 99.0;
+    data.zero_conditions = Some(ZeroConditions {
+        temperature: 39.0,
+        pressure: 29.92,
+        humidity: 0.0,
+        altitude: 0.0,
+    });
+    assert!((data.zero_data().muzzle_velocity() - 2570.0).abs() < 1e-9);
+
+    // The zero is the one a 2570 ft/s load takes at 39 °F
+    let mut cold = reference_shot(DragModel::G1, 0.505);
+    cold.velocity = 2570.0;
+    cold.temperature = 39.0;
+    let angle = calculator.calculate_zero_angle(&data).unwrap();
+    assert!((angle - calculator.calculate_zero_angle(&cold).unwrap()).abs() < 1e-6);
+
+    // Faster ammunition in thinner air then shoots high at the zero range
+    data.range_table.spec = RangeSpec::List(vec![100.0]);
+    let point = &calculator.calculate(&data).trajectory_points[0];
+    assert!(point.drop > 0.05, "{:.3} in at the 100 yd zero", point.drop);
+}
+
+#[test]
+fn range_table_interval_includes_end() {
+    let table = RangeTable::interval(RangeUnit::Yards, 0.0, 250.0, 100.0);
+    assert_eq!(table.distances().unwrap(), vec![0.0, 100.0, 200.0, 250.0]);
+}
+
+#[test]
+fn range_table_rejects_non_finite_intervals() {
+    for (start, end, step) in [
+        (0.0, f64::INFINITY, 1.0),
+        (f64::NAN, 1000.0, 100.0),
+        (0.0, f64::NAN, 100.0),
+        (0.0, 1000.0, f64::NAN),
+        (0.0, 1000.0, f64::INFINITY),
+    ] {
+        let table = RangeTable::interval(RangeUnit::Yards, start, end, step);
+        assert!(
+            matches!(table.distances(), Err(BallisticsError::InvalidRangeTable(_))),
+            "interval {}-{} step {} was accepted",
+            start,
+            end,
+            step
+        );
+    }
+}
+
+#[test]
+fn range_table_rejects_too_many_rows() {
+    let table = RangeTable::interval(RangeUnit::Yards, 0.0, 1000.0, 1e-300);
+    assert!(matches!(table.distances(), Err(BallisticsError::InvalidRangeTable(_))));
+    let table = RangeTable::interval(RangeUnit::Yards, 0.0, 1e308, 1e-308);
+    assert!(matches!(table.distances(), Err(BallisticsError::InvalidRangeTable(_))));
+}
+
+#[test]
+fn range_table_meters_list_is_sorted_and_converted() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.range_table = RangeTable {
+        unit: RangeUnit::Meters,
+        spec: RangeSpec::List(vec![300.0, 100.0, 200.0, 100.0]),
+    };
+    assert_eq!(data.range_table.distances().unwrap(), vec![100.0, 200.0, 300.0]);
+
+    let result = BallisticsCalculator::default().calculate(&data);
+    let distances: Vec<f64> = result.trajectory_points.iter().map(|p| p.distance).collect();
+    assert_eq!(distances, vec![100.0, 200.0, 300.0]);
+    assert!((RangeUnit::Meters.to_yards(100.0) - 109.361).abs() < 0.001);
+}
+
+#[test]
+fn truing_recovers_velocity_and_bc_from_synthetic_drops() {
+    let mut actual = reference_shot(DragModel::G1, 0.470);
+    actual.velocity = 2660.0;
+    actual.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![300.0, 500.0, 700.0, 900.0]),
+    };
+    let observations: Vec<Observation> = BallisticsCalculator::default()
+        .calculate(&actual)
+        .trajectory_points
+        .iter()
+        .map(|p| Observation {
+            range: p.distance,
+            drop: p.drop,
+        })
+        .collect();
+
+    let data = reference_shot(DragModel::G1, 0.505);
+    let result = BallisticsCalculator::default()
+        .true_trajectory(&data, &observations, TruingMode::Both)
+        .unwrap();
+    assert!((result.velocity - 2660.0).abs() < 2.0, "velocity {:.1}", result.velocity);
+    assert!((result.bc - 0.470).abs() < 0.002, "bc {:.4}", result.bc);
+    assert!(result.rms_after < 0.05, "rms after {:.3}", result.rms_after);
+    assert!(result.rms_after < result.rms_before);
+}
+
+#[test]
+fn truing_ignores_observations_at_the_zero_range() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let observations = [Observation {
+        range: data.zero_range,
+        drop: 0.0,
+    }];
+    let result =
+        BallisticsCalculator::default().true_trajectory(&data, &observations, TruingMode::Velocity);
+    assert!(matches!(result, Err(BallisticsError::Truing(_))));
+}
+
+#[test]
+fn truing_rejects_observations_that_do_not_constrain_the_fit() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    // Flatter than any BC can shoot, so the fit runs the BC up until the
+    // drop no longer responds to it
+    let observations = [Observation {
+        range: 300.0,
+        drop: -1.0,
+    }];
+    let result =
+        BallisticsCalculator::default().true_trajectory(&data, &observations, TruingMode::Bc);
+    assert!(matches!(result, Err(BallisticsError::Truing(_))), "{:?}", result.map(|r| r.bc));
+}
+
+#[test]
+fn truing_record_applies_scale_to_a_custom_curve() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.drag_curve = Some(DragCurve {
+        id: "curve".to_string(),
+        name: "curve".to_string(),
+        diameter: 0.308,
+        points: vec![(0.5, 0.25), (1.0, 0.40), (3.0, 0.30)],
+        notes: String::new(),
+    });
+    let record = TruingRecord {
+        timestamp: String::new(),
+        load: None,
+        drag_model: Some(DragModel::G1),
+        velocity: 2650.0,
+        bc: 0.5555,
+        bc_scale: 1.1,
+        bc_segments: Vec::new(),
+        mode: TruingMode::Both,
+        observations: Vec::new(),
+        conditions: data.atmosphere(),
+        rms_after: 0.0,
+    };
+    record.apply(&mut data);
+    assert_eq!(data.velocity, 2650.0);
+    assert_eq!(data.bc, 0.5555);
+    let cd = data.drag_curve.unwrap().points[1].1;
+    assert!((cd - 0.40 / 1.1).abs() < 1e-12);
+}
+
+#[test]
+fn truing_record_without_scale_defaults_to_one() {
+    let mut record = serde_json::to_value(TruingRecord {
+        timestamp: String::new(),
+        load: None,
+        drag_model: None,
+        velocity: 2600.0,
+        bc: 0.505,
+        bc_scale: 1.2,
+        bc_segments: Vec::new(),
+        mode: TruingMode::Velocity,
+        observations: Vec::new(),
+        conditions: reference_shot(DragModel::G1, 0.505).atmosphere(),
+        rms_after: 0.0,
+    })
+    .unwrap();
+    record.as_object_mut().unwrap().remove("bc_scale");
+    let record: TruingRecord = serde_json::from_value(record).unwrap();
+    assert_eq!(record.bc_scale, 1.0);
+}
+
+#[test]
+fn bc_segments_parse_sorted_fastest_first() {
+    let segments = BcSegment::parse_text("0:0.485; 2800:0.505;1800:0.496;bad;1200:");
+    assert_eq!(
+        segments,
+        vec![
+            BcSegment { velocity: 2800.0, bc: 0.505 },
+            BcSegment { velocity: 1800.0, bc: 0.496 },
+            BcSegment { velocity: 0.0, bc: 0.485 },
+        ]
+    );
+    assert_eq!(BcSegment::parse_text(&BcSegment::to_text(&segments)), segments);
+}
+
+#[test]
+fn bc_at_band_edges() {
+    let segments = BcSegment::parse_text("2800:0.505;1800:0.496;1200:0.485");
+    assert_eq!(BcSegment::bc_at(&segments, 3000.0), Some(0.505));
+    assert_eq!(BcSegment::bc_at(&segments, 2800.0), Some(0.505));
+    assert_eq!(BcSegment::bc_at(&segments, 2799.9), Some(0.496));
+    assert_eq!(BcSegment::bc_at(&segments, 1800.0), Some(0.496));
+    assert_eq!(BcSegment::bc_at(&segments, 1200.0), Some(0.485));
+    // Below the slowest threshold the slowest band still applies
+    assert_eq!(BcSegment::bc_at(&segments, 900.0), Some(0.485));
+    assert_eq!(BcSegment::bc_at(&[], 900.0), None);
+}
+
+#[test]
+fn bc_segments_replace_the_single_bc() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let mut banded = data.clone();
+    banded.bc_segments = BcSegment::parse_text("2800:0.505;0:0.400");
+    let plain = BallisticsCalculator::default().calculate(&data);
+    let banded = BallisticsCalculator::default().calculate(&banded);
+    // Below 2800 ft/s the lower band applies, so the bullet slows faster
+    let last = |r: &TrajectoryResult| r.trajectory_points.last().unwrap().velocity;
+    assert!(last(&banded) < last(&plain) - 50.0);
+}
+
+#[test]
+fn twist_rate_parses_common_forms() {
+    let parse = |s: &str| s.parse::<TwistRate>().unwrap();
+    for s in ["1:10", "1/10", "10", "1 in 10", "1-10 RH"] {
+        assert_eq!(parse(s), TwistRate { inches: 10.0, direction: TwistDirection::Right }, "{}", s);
+    }
+    assert_eq!(parse("1:7.5 LH"), TwistRate { inches: 7.5, direction: TwistDirection::Left });
+    assert_eq!(parse("1 in 8 left").direction, TwistDirection::Left);
+    assert_eq!(parse("2:20").inches, 10.0);
+    for s in ["", "fast", "0", "1:0"] {
+        assert!(s.parse::<TwistRate>().is_err(), "{:?} was accepted", s);
+    }
+}
+
+#[test]
+fn miller_stability_for_a_175_grain_308() {
+    let twist = TwistRate { inches: 10.0, direction: TwistDirection::Right };
+    // 30 m / (t² d³ l (1 + l²)), t and l in calibers, times (v / 2800)^(1/3)
+    let sg = twist.miller_stability(175.0, 0.308, 1.24, 2600.0, 1.0).unwrap();
+    assert!((sg - 2.40).abs() < 0.01, "sg {:.3}", sg);
+    // Thinner air stabilizes more
+    assert!(twist.miller_stability(175.0, 0.308, 1.24, 2600.0, 0.8).unwrap() > sg);
+    assert!(twist.miller_stability(175.0, 0.308, 0.0, 2600.0, 1.0).is_none());
+}
+
+#[test]
+fn coriolis_drifts_right_and_eotvos_lifts_eastward_fire_in_the_north() {
+    let calculator = BallisticsCalculator {
+        include_coriolis: true,
+        ..Default::default()
+    };
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.latitude = 45.0;
+    let fire = |azimuth: f64| {
+        let mut data = data.clone();
+        data.azimuth = azimuth;
+        calculator.calculate(&data).trajectory_points[1].clone()
+    };
+    let (north, east, west) = (fire(0.0), fire(90.0), fire(270.0));
+
+    // Horizontal deflection is right in the northern hemisphere, about 2.8 in
+    // at 1000 yd. Ω·sin(latitude)·range·time holds at constant velocity; the
+    // slowing bullet spends less of its flight far downrange
+    let bound = EARTH_ROTATION * 45f64.to_radians().sin() * 3000.0 * north.time * 12.0;
+    assert!(
+        (2.6..3.0).contains(&north.coriolis_drift) && north.coriolis_drift < bound,
+        "{:.2} in of Coriolis drift at 1000 yd, constant-velocity bound {:.2}",
+        north.coriolis_drift,
+        bound
+    );
+    assert!((north.drift - north.coriolis_drift).abs() < 1e-6);
+
+    // Eastward fire rises and westward fire falls by about the same amount
+    assert!(north.eotvos_drop.abs() < 0.1, "{}", north.eotvos_drop);
+    assert!(east.eotvos_drop > 1.0, "{}", east.eotvos_drop);
+    assert!(west.eotvos_drop < -1.0, "{}", west.eotvos_drop);
+    assert!((east.eotvos_drop + west.eotvos_drop).abs() < 0.1 * east.eotvos_drop);
+    assert!(east.drop > west.drop);
+
+    // Switched off, latitude and azimuth leave the trajectory alone
+    data.azimuth = 90.0;
+    let off = BallisticsCalculator::default().calculate(&data).trajectory_points[1].clone();
+    let plain = BallisticsCalculator::default()
+        .calculate(&reference_shot(DragModel::G1, 0.505))
+        .trajectory_points[1]
+        .clone();
+    assert_eq!(off.coriolis_drift, 0.0);
+    assert_eq!(off.eotvos_drop, 0.0);
+    assert_eq!(off.drift, plain.drift);
+    assert_eq!(off.drop, plain.drop);
+}
+
+#[test]
+fn inclined_shots_drop_less_than_level() {
+    let calculator = BallisticsCalculator::default();
+    let level = calculator.calculate(&reference_shot(DragModel::G1, 0.505));
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    for angle in [30.0, -30.0] {
+        data.shot_angle = angle;
+        let result = calculator.calculate(&data);
+        assert_eq!(result.shot_angle, angle);
+        for (point, flat) in result.trajectory_points.iter().zip(&level.trajectory_points) {
+            assert_eq!(point.distance, flat.distance);
+            assert!(
+                point.drop > flat.drop && point.drop < 0.0,
+                "{:.1} in at {} yd and {}°, level {:.1} in",
+                point.drop,
+                point.distance,
+                angle,
+                flat.drop
+            );
+            let horizontal = point.distance * 30f64.to_radians().cos();
+            assert!((point.horizontal_distance - horizontal).abs() < 1e-9);
+        }
+    }
+    for point in &level.trajectory_points {
+        assert_eq!(point.horizontal_distance, point.distance);
+    }
+}
+
+#[test]
+fn one_wind_zone_over_the_whole_path_is_plain_wind() {
+    let calculator = BallisticsCalculator::default();
+    let mut plain = reference_shot(DragModel::G1, 0.505);
+    plain.wind_speed = 10.0;
+    plain.wind_angle = 90.0;
+    let mut zoned = reference_shot(DragModel::G1, 0.505);
+    zoned.wind_zones = vec![WindZone {
+        start: 0.0,
+        end: 2000.0,
+        speed: 10.0,
+        angle: 90.0,
+        vertical: 0.0,
+    }];
+
+    let plain = calculator.calculate(&plain);
+    let zoned = calculator.calculate(&zoned);
+    for (a, b) in plain.trajectory_points.iter().zip(&zoned.trajectory_points) {
+        assert!(a.drift < -1.0);
+        assert!((a.drift - b.drift).abs() < 1e-6, "{} vs {} in", a.drift, b.drift);
+        assert!((a.drop - b.drop).abs() < 1e-6);
+        assert_eq!(b.zone_drift.len(), 1);
+        assert!((b.zone_drift[0] - b.drift).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn zone_drifts_add_up_to_the_wind_drift() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    let zone = |start: f64, end: f64, speed: f64, angle: f64| WindZone {
+        start,
+        end,
+        speed,
+        angle,
+        vertical: 0.0,
+    };
+    data.wind_zones = vec![
+        zone(0.0, 300.0, 5.0, 90.0),
+        zone(300.0, 700.0, 12.0, 270.0),
+        zone(700.0, 1000.0, 8.0, 45.0),
+    ];
+    let result = BallisticsCalculator::default().calculate(&data);
+    let point = &result.trajectory_points[1];
+    assert_eq!(point.zone_drift.len(), 3);
+    // Right-hand wind near the muzzle pushes left, the stronger left-hand
+    // wind mid-range pushes right
+    assert!(point.zone_drift[0] < 0.0 && point.zone_drift[1] > 0.0 && point.zone_drift[2] < 0.0);
+    let sum: f64 = point.zone_drift.iter().sum();
+    assert!(
+        (sum - point.drift).abs() < 0.02 * point.drift.abs().max(1.0),
+        "zones sum to {:.2} in, drift {:.2} in",
+        sum,
+        point.drift
+    );
+
+    // Zones that have not started by a range drift nothing there
+    assert_eq!(result.trajectory_points[0].zone_drift[2], 0.0);
+}
+
+#[test]
+fn wind_holds_scale_with_wind_speed() {
+    let calculator = BallisticsCalculator::default();
+    let data = reference_shot(DragModel::G1, 0.505);
+    let result = calculator.calculate(&data);
+
+    // The full-value bracket matches a 10 mph wind from the left
+    let mut crosswind = data.clone();
+    crosswind.wind_speed = 10.0;
+    crosswind.wind_angle = 270.0;
+    let crosswind = calculator.calculate(&crosswind);
+
+    for (point, windy) in result.trajectory_points.iter().zip(&crosswind.trajectory_points) {
+        assert_eq!(point.wind_holds.len(), WIND_BRACKET_SPEEDS.len());
+        let full = point.wind_holds.iter().find(|h| h.speed == 10.0).unwrap();
+        assert!((full.drift - windy.drift).abs() < 1e-6, "{} vs {} in", full.drift, windy.drift);
+
+        // Drift at these speeds is proportional to the wind
+        for hold in &point.wind_holds {
+            let scaled = full.drift * hold.speed / 10.0;
+            assert!((hold.drift - scaled).abs() < 0.01 * full.drift, "{:?}", hold);
+            let (moa, mil) = angular_adjustment(hold.drift, point.distance);
+            assert!((hold.moa - moa).abs() < 1e-9 && (hold.mil - mil).abs() < 1e-9);
+        }
+
+        // Range in hundreds of yards times 10 mph, over the 10 mph hold in MOA
+        let constant = point.distance / 100.0 * 10.0 / full.moa;
+        assert!((point.wind_constant - constant).abs() < 1e-9);
+        assert!((5.0..20.0).contains(&point.wind_constant), "{}", point.wind_constant);
+    }
+}
+
+#[test]
+fn spin_drift_follows_the_twist() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.bullet_diameter = 0.308;
+    data.bullet_length = 1.24;
+    data.twist = Some(TwistRate { inches: 10.0, direction: TwistDirection::Right });
+    let right = BallisticsCalculator::default().calculate(&data);
+    data.twist = Some(TwistRate { inches: 10.0, direction: TwistDirection::Left });
+    let left = BallisticsCalculator::default().calculate(&data);
+
+    assert!(right.stability.is_some());
+    let (r, l) = (&right.trajectory_points[1], &left.trajectory_points[1]);
+    assert!(r.spin_drift > 5.0, "spin drift {:.2} in at 1000 yd", r.spin_drift);
+    assert!((r.spin_drift + l.spin_drift).abs() < 1e-9);
+    assert!((r.drift - r.spin_drift).abs() < 1e-6);
+}
+
+#[test]
+fn aerodynamic_jump_follows_the_twist_in_a_crosswind() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.bullet_diameter = 0.308;
+    data.bullet_length = 1.24;
+    data.wind_speed = 10.0;
+    data.wind_angle = 90.0; // right to left
+    let calculator = BallisticsCalculator::default();
+    let shoot = |data: &ProjectileData, direction: TwistDirection| {
+        let mut data = data.clone();
+        data.twist = Some(TwistRate { inches: 10.0, direction });
+        calculator.calculate(&data)
+    };
+    let right = shoot(&data, TwistDirection::Right);
+    let left = shoot(&data, TwistDirection::Left);
+
+    // Litz: 0.01·Sg − 0.0024·L + 0.032 MOA per mph, L in calibers
+    let sg = right.stability.unwrap();
+    let moa = (0.01 * sg - 0.0024 * 1.24 / 0.308 + 0.032) * 10.0;
+    let (r, l) = (&right.trajectory_points[1], &left.trajectory_points[1]);
+    let expected = (moa / 60.0).to_radians().tan() * 3000.0 * 12.0;
+    assert!(r.aerodynamic_jump > 0.0, "{:.2} in", r.aerodynamic_jump);
+    assert!((r.aerodynamic_jump - expected).abs() < 1e-6, "{} vs {}", r.aerodynamic_jump, expected);
+    assert!((r.aerodynamic_jump + l.aerodynamic_jump).abs() < 1e-9);
+    assert!(r.drop > l.drop);
+
+    // The other way across, right twist jumps down
+    data.wind_angle = 270.0;
+    let across = shoot(&data, TwistDirection::Right);
+    assert!((across.trajectory_points[1].aerodynamic_jump + r.aerodynamic_jump).abs() < 1e-9);
+
+    // No crosswind, no jump
+    data.wind_angle = 0.0;
+    assert_eq!(shoot(&data, TwistDirection::Right).trajectory_points[1].aerodynamic_jump, 0.0);
+}
+
+#[test]
+fn cant_moves_impact_right_and_low() {
+    let calculator = BallisticsCalculator::default();
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    let level = calculator.calculate(&data);
+    for point in &level.trajectory_points {
+        assert_eq!((point.cant_drop, point.cant_drift), (0.0, 0.0));
+    }
+
+    data.cant = 5.0;
+    let canted = calculator.calculate(&data);
+    let zero_angle = calculator.calculate_zero_angle(&data).unwrap();
+    let cant = 5f64.to_radians();
+    for (point, flat) in canted.trajectory_points.iter().zip(&level.trajectory_points) {
+        // Fall from the bore line, which the sight no longer holds vertical
+        let fall = point.distance * 36.0 * zero_angle.tan() - data.sight_height - point.drop;
+        assert!(point.cant_drift > 0.0 && point.cant_drop < 0.0);
+        assert!((point.cant_drift - fall * cant.sin()).abs() < 1e-9);
+        assert!((point.cant_drop + fall * (1.0 - cant.cos())).abs() < 1e-9);
+        // Reported on their own, not folded into drop and drift
+        assert_eq!(point.drop, flat.drop);
+        assert_eq!(point.drift, flat.drift);
+    }
+
+    // At 1000 yd, 5° of cant is worth several inches
+    let far = &canted.trajectory_points[1];
+    assert!((10.0..40.0).contains(&far.cant_drift), "{:.1} in", far.cant_drift);
+
+    data.cant = -5.0;
+    let left = &calculator.calculate(&data).trajectory_points[1];
+    assert!((left.cant_drift + far.cant_drift).abs() < 1e-9);
+    assert!((left.cant_drop - far.cant_drop).abs() < 1e-9);
+}
+
+#[test]
+fn seeded_dispersion_is_reproducible() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let uncertainty = Uncertainty {
+        samples: 200,
+        seed: Some(7),
+        ..Default::default()
+    };
+    let target = TargetShape::default();
+    let calculator = BallisticsCalculator::default();
+    let first = calculator.dispersion(&data, &uncertainty, &target).unwrap();
+    let second = calculator.dispersion(&data, &uncertainty, &target).unwrap();
+    assert_eq!(first, second);
+
+    let other = Uncertainty {
+        seed: Some(8),
+        ..uncertainty
+    };
+    assert_ne!(first, calculator.dispersion(&data, &other, &target).unwrap());
+
+    // Spread and misses grow with range
+    let (near, far) = (&first.points[0], &first.points[1]);
+    assert!(far.vertical_sd > near.vertical_sd);
+    assert!(far.horizontal_sd > near.horizontal_sd);
+    assert!(far.hit_probability <= near.hit_probability);
+}
+
+#[test]
+fn dispersion_without_uncertainty_hits_the_point_of_aim() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let uncertainty = Uncertainty {
+        velocity_sd: 0.0,
+        bc_sd: 0.0,
+        range_sd: 0.0,
+        wind_speed_sd: 0.0,
+        wind_angle_sd: 0.0,
+        precision_moa: 0.0,
+        samples: 5,
+        seed: Some(1),
+    };
+    let result = BallisticsCalculator::default()
+        .dispersion(&data, &uncertainty, &TargetShape::Circle { diameter: 1.0 })
+        .unwrap();
+    for point in &result.points {
+        assert!(point.vertical_mean.abs() < 0.01 && point.horizontal_mean.abs() < 0.01);
+        assert!(point.vertical_sd < 1e-9 && point.horizontal_sd < 1e-9);
+        assert_eq!(point.hit_probability, 1.0);
+    }
+}
+
+#[test]
+fn point_blank_range_peaks_at_half_the_vital_zone() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let pbr = BallisticsCalculator::default().point_blank_range(&data, 8.0).unwrap();
+    assert!((pbr.max_ordinate - 4.0).abs() < 0.01, "max ordinate {:.3} in", pbr.max_ordinate);
+    assert!(pbr.near_limit <= pbr.near_zero);
+    assert!(pbr.near_zero < pbr.max_ordinate_range);
+    assert!(pbr.max_ordinate_range < pbr.zero_range);
+    assert!(pbr.zero_range < pbr.far_limit);
+
+    // Sighted in at the far zero, the table agrees with the limits
+    let mut zeroed = data.clone();
+    zeroed.zero_range = pbr.zero_range;
+    zeroed.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![pbr.max_ordinate_range, pbr.far_limit]),
+    };
+    let result = BallisticsCalculator::default().calculate(&zeroed);
+    assert!((result.max_ordinate - 4.0).abs() < 0.05, "max ordinate {:.3} in", result.max_ordinate);
+    let far = &result.trajectory_points[1];
+    assert!((far.drop + 4.0).abs() < 0.05, "drop at far limit {:.3} in", far.drop);
+}
+
+#[test]
+fn point_blank_range_rejects_unreachable_zones() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let calculator = BallisticsCalculator::default();
+    for zone in [0.0, -4.0, f64::NAN] {
+        assert!(matches!(
+            calculator.point_blank_range(&data, zone),
+            Err(BallisticsError::PointBlankRange(_))
+        ));
+    }
+}
+
+#[test]
+fn lead_is_target_travel_over_the_time_of_flight() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.bullet_diameter = 0.308;
+    data.bullet_length = 1.24;
+    data.twist = Some(TwistRate { inches: 10.0, direction: TwistDirection::Right });
+    data.moving_target = Some(MovingTarget { speed: 3.0, angle: 90.0, length: 36.0 });
+
+    // Spin drift is held off like on any shot, and wind along the target axis
+    // drifts nothing, so the lead is just how far the target walks
+    for (speed, angle) in [(0.0, 0.0), (10.0, 0.0), (10.0, 180.0)] {
+        data.wind_speed = speed;
+        data.wind_angle = angle;
+        let result = BallisticsCalculator::default().calculate(&data);
+        for point in &result.trajectory_points {
+            assert!(point.spin_drift > 0.0);
+            let lead = point.lead.as_ref().unwrap();
+            let travel = 3.0 * MPH_TO_FPS * point.time * 12.0;
+            assert!(
+                (lead.inches - travel).abs() < 0.01,
+                "lead {:.2} in, target travel {:.2} in at {} yd in a {} mph wind from {}°",
+                lead.inches,
+                travel,
+                point.distance,
+                speed,
+                angle
+            );
+            assert!((lead.lengths - lead.inches / 36.0).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn crosswind_drift_shortens_the_lead_downwind() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.moving_target = Some(MovingTarget { speed: 3.0, angle: 90.0, length: 36.0 });
+    data.wind_speed = 10.0;
+    data.wind_angle = 270.0; // from the left, carrying the bullet with the target
+    let result = BallisticsCalculator::default().calculate(&data);
+    for point in &result.trajectory_points {
+        let travel = 3.0 * MPH_TO_FPS * point.time * 12.0;
+        let lead = point.lead.as_ref().unwrap();
+        assert!(point.drift > 0.0);
+        assert!((lead.inches - (travel - point.drift)).abs() < 0.01);
+    }
+}
+
+#[test]
+fn danger_space_brackets_the_aim_range() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.target_height = Some(20.0);
+    data.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![0.0, 300.0, 600.0]),
+    };
+    let result = BallisticsCalculator::default().calculate(&data);
+    let points = &result.trajectory_points;
+    assert!(points[0].danger_space.is_none());
+
+    let near = points[1].danger_space.unwrap();
+    let far = points[2].danger_space.unwrap();
+    assert!(near.near < 300.0 && 300.0 < near.far, "{:?}", near);
+    assert!(far.near < 600.0 && 600.0 < far.far, "{:?}", far);
+    assert!((near.depth - (near.far - near.near)).abs() < 1e-9);
+    // The bullet falls more steeply further out
+    assert!(far.depth < near.depth);
+
+    // At either edge the bullet is half the target height off the aim line
+    let aim_drop = points[2].drop;
+    let mut edges = data.clone();
+    edges.target_height = None;
+    edges.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![far.near, far.far]),
+    };
+    let edges = BallisticsCalculator::default().calculate(&edges);
+    assert_eq!(edges.trajectory_points.len(), 2);
+    for point in &edges.trajectory_points {
+        let offset = point.drop - aim_drop * point.distance / 600.0;
+        assert!(
+            (offset.abs() - 10.0).abs() < 0.05,
+            "{:.3} in off the aim line at {:.1} yd",
+            offset,
+            point.distance
+        );
+    }
+}
+
+#[test]
+fn small_target_danger_space_hugs_the_aim_range() {
+    // A 2" target at 600 yd is shallower than a single solver step
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.target_height = Some(2.0);
+    data.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![600.0]),
+    };
+    let result = BallisticsCalculator::default().calculate(&data);
+    let aim_drop = result.trajectory_points[0].drop;
+    let space = result.trajectory_points[0].danger_space.unwrap();
+    assert!(space.near < 600.0 && 600.0 < space.far, "{:?}", space);
+    assert!(space.depth < 10.0, "{:?}", space);
+
+    let mut edges = data.clone();
+    edges.target_height = None;
+    edges.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![space.near, space.far]),
+    };
+    let edges = BallisticsCalculator::default().calculate(&edges);
+    for point in &edges.trajectory_points {
+        let offset = point.drop - aim_drop * point.distance / 600.0;
+        assert!(
+            (offset.abs() - 1.0).abs() < 0.02,
+            "{:.3} in off the aim line at {:.1} yd",
+            offset,
+            point.distance
+        );
+    }
+}
+
+#[test]
+fn no_danger_space_without_a_target_height() {
+    let result = BallisticsCalculator::default().calculate(&reference_shot(DragModel::G1, 0.505));
+    assert!(result.trajectory_points.iter().all(|p| p.danger_space.is_none()));
+}
+
+#[test]
+fn turret_warns_when_elevation_runs_out() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.turret = Some(ScopeTurret {
+        max_elevation: 40.0,
+        ..Default::default()
+    });
+    let result = BallisticsCalculator::default().calculate(&data);
+    // About 39 MOA at 1000 yards, with 20 MOA of travel above centre plus
+    // the zero's offset below it
+    let clicks = result.trajectory_points[1].clicks.unwrap();
+    assert!(clicks.elevation > 4.0 * 35.0);
+    assert!(
+        result.warnings.iter().any(|w| w.contains("1000 yards exceeds")),
+        "{:?}",
+        result.warnings
+    );
+
+    data.turret = Some(ScopeTurret {
+        max_elevation: 100.0,
+        ..Default::default()
+    });
+    let result = BallisticsCalculator::default().calculate(&data);
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+}
+
+#[test]
+fn turret_warns_when_the_base_needs_more_down_travel() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.turret = Some(ScopeTurret {
+        max_elevation: 40.0,
+        base_cant: 40.0,
+        ..Default::default()
+    });
+    let result = BallisticsCalculator::default().calculate(&data);
+    assert!(result.warnings.iter().any(|w| w.contains("down travel")), "{:?}", result.warnings);
+}
+
+#[test]
+fn ethical_range_is_where_the_first_minimum_is_crossed() {
+    let calculator = BallisticsCalculator::default();
+    let mut data = reference_shot(DragModel::G1, 0.505);
+
+    data.game_class = Some(GameClass::presets()[1].clone()); // Deer, 1800 ft/s
+    let ethical = calculator.calculate(&data).ethical_range.unwrap();
+    assert_eq!(ethical.limit, TerminalLimit::Velocity);
+    let mut at = data.clone();
+    at.range_table = RangeTable {
+        unit: RangeUnit::Yards,
+        spec: RangeSpec::List(vec![ethical.range]),
+    };
+    let velocity = calculator.calculate(&at).trajectory_points[0].velocity;
+    assert!((velocity - 1800.0).abs() < 1.0, "{:.1} ft/s at {:.0} yd", velocity, ethical.range);
+
+    data.game_class = Some(GameClass::presets()[2].clone()); // Elk, 1500 ft-lbs
+    let ethical = calculator.calculate(&data).ethical_range.unwrap();
+    assert_eq!(ethical.limit, TerminalLimit::Energy);
+    at.range_table.spec = RangeSpec::List(vec![ethical.range]);
+    let energy = calculator.calculate(&at).trajectory_points[0].energy;
+    assert!((energy - 1500.0).abs() < 2.0, "{:.1} ft-lbs at {:.0} yd", energy, ethical.range);
+
+    // Already below the minimum at the muzzle
+    data.game_class = Some(GameClass::presets()[3].clone());
+    data.velocity = 2000.0;
+    let result = calculator.calculate(&data);
+    assert_eq!(result.ethical_range.unwrap().range, 0.0);
+    assert!(!result.warnings.is_empty());
+}
+
+#[test]
+fn momentum_and_taylor_ko_at_the_muzzle() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.bullet_diameter = 0.308;
+    data.range_table.spec = RangeSpec::List(vec![0.0]);
+    let point = &BallisticsCalculator::default().calculate(&data).trajectory_points[0];
+    // TKO = grains × ft/s × inches / 7000
+    assert!((point.taylor_ko - 20.02).abs() < 0.01, "{}", point.taylor_ko);
+    assert!((point.momentum - 0.025 * 2600.0 / GRAVITY).abs() < 1e-6, "{}", point.momentum);
+}
+
+#[test]
+fn maximum_range_peaks_between_30_and_40_degrees() {
+    let data = reference_shot(DragModel::G1, 0.505);
+    let calculator = BallisticsCalculator::default();
+    let max = calculator.maximum_range(&data).unwrap();
+    assert!((30.0..=40.0).contains(&max.angle), "angle {:.1}°", max.angle);
+    assert!((3000.0..6000.0).contains(&max.distance), "distance {:.0} yd", max.distance);
+    assert!(max.impact_velocity < data.velocity);
+    assert!(max.impact_angle > max.angle, "impact {:.1}°", max.impact_angle);
+    assert!(max.apex > 0.0 && max.time > 0.0);
+}
+
+#[test]
+fn maximum_range_needs_a_muzzle_velocity() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.velocity = 0.0;
+    assert!(matches!(
+        BallisticsCalculator::default().maximum_range(&data),
+        Err(BallisticsError::MaximumRange(_))
+    ));
+}
+
+#[test]
+fn mach_is_measured_against_the_air() {
+    let mut data = reference_shot(DragModel::G1, 0.505);
+    data.wind_speed = 20.0;
+    data.wind_angle = 0.0; // from the target
+    let calculator = BallisticsCalculator::default();
+    let result = calculator.calculate(&data);
+
+    for point in &result.trajectory_points {
+        let airspeed = point.mach * result.speed_of_sound;
+        assert!(
+            (airspeed - point.velocity - 20.0 * MPH_TO_FPS).abs() < 0.5,
+            "airspeed {:.1} ft/s, ground speed {:.1} ft/s",
+            airspeed,
+            point.velocity
+        );
+    }
+
+    // The transition ranges agree with the reported Mach
+    let transonic = result.transonic_range.unwrap();
+    let subsonic = result.subsonic_range.unwrap();
+    data.range_table.spec = RangeSpec::List(vec![transonic, subsonic]);
+    let at = calculator.calculate(&data);
+    assert!((at.trajectory_points[0].mach - 1.2).abs() < 1e-3, "{}", at.trajectory_points[0].mach);
+    assert!((at.trajectory_points[1].mach - 1.0).abs() < 1e-3, "{}", at.trajectory_points[1].mach);
+}
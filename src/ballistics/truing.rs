@@ -0,0 +1,314 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Atmosphere, BallisticsCalculator, BallisticsError, BcSegment, DragModel, ProjectileData,
+};
+
+const TRUING_MAX_ITERATIONS: usize = 25;
+const VELOCITY_STEP: f64 = 1.0; // ft/s, finite difference for the Jacobian
+const BC_SCALE_STEP: f64 = 0.001;
+const ZERO_RANGE_MARGIN: f64 = 1.0; // yards; drops this close to the zero carry no information
+const MIN_SENSITIVITY: f64 = 1e-6; // inches of RMS drop change per step, below is solver noise
+
+/// A measured point of impact from the field.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    pub range: f64, // yards
+    pub drop: f64,  // inches, negative = below line of sight
+}
+
+/// Which inputs the fit may adjust.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum TruingMode {
+    #[default]
+    Velocity,
+    Bc,
+    Both,
+}
+
+impl TruingMode {
+    fn fits_velocity(self) -> bool {
+        matches!(self, TruingMode::Velocity | TruingMode::Both)
+    }
+
+    fn fits_bc(self) -> bool {
+        matches!(self, TruingMode::Bc | TruingMode::Both)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TruingResult {
+    pub mode: TruingMode,
+    pub velocity: f64, // ft/s
+    pub bc: f64,
+    pub bc_scale: f64, // trued / original BC, also applied to segments and curves
+    pub bc_segments: Vec<BcSegment>,
+    pub observations: Vec<Observation>,
+    pub residuals_before: Vec<f64>, // inches, observed - predicted
+    pub residuals_after: Vec<f64>,  // inches
+    pub rms_before: f64,
+    pub rms_after: f64,
+}
+
+/// Trued values with the field data and conditions they were derived from,
+/// for storing on a firearm profile or load. Only valid for the load and drag
+/// model the fit was made against.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TruingRecord {
+    pub timestamp: String,
+    #[serde(default)]
+    pub load: Option<String>, // key of the library load fitted, None if none was applied
+    #[serde(default)]
+    pub drag_model: Option<DragModel>, // None on records saved before it was kept
+    pub velocity: f64,
+    pub bc: f64,
+    #[serde(default = "unit_scale")]
+    pub bc_scale: f64, // also divides the Cd of a custom drag curve
+    pub bc_segments: Vec<BcSegment>,
+    pub mode: TruingMode,
+    pub observations: Vec<Observation>,
+    pub conditions: Atmosphere,
+    pub rms_after: f64,
+}
+
+impl TruingResult {
+    pub fn record(
+        &self,
+        data: &ProjectileData,
+        load: Option<String>,
+        timestamp: String,
+    ) -> TruingRecord {
+        TruingRecord {
+            timestamp,
+            load,
+            drag_model: Some(data.drag_model),
+            velocity: self.velocity,
+            bc: self.bc,
+            bc_scale: self.bc_scale,
+            bc_segments: self.bc_segments.clone(),
+            mode: self.mode,
+            observations: self.observations.clone(),
+            conditions: data.atmosphere(),
+            rms_after: self.rms_after,
+        }
+    }
+
+    /// Copy the trued values into `data`.
+    pub fn apply(&self, data: &mut ProjectileData) {
+        *data = scaled(data, self.velocity, self.bc_scale);
+    }
+}
+
+impl TruingRecord {
+    /// Whether this record applies to `load` (its library key, if any) flown
+    /// with `drag_model`. A velocity and BC fitted to one load or reference
+    /// projectile say nothing about another.
+    pub fn matches(&self, load: Option<&str>, drag_model: DragModel) -> bool {
+        self.load.as_deref() == load && self.drag_model == Some(drag_model)
+    }
+
+    /// Copy the trued values into `data`, whose drag curve, if any, must be
+    /// the untrued one.
+    pub fn apply(&self, data: &mut ProjectileData) {
+        *data = scaled(data, self.velocity, self.bc_scale);
+        data.bc = self.bc;
+        data.bc_segments = self.bc_segments.clone();
+    }
+}
+
+fn unit_scale() -> f64 {
+    1.0
+}
+
+impl BallisticsCalculator {
+    /// Fit muzzle velocity and/or a BC scale factor so the predicted drops
+    /// match `observations` in the least-squares sense (Gauss-Newton with a
+    /// finite-difference Jacobian). The zero is re-solved for each trial, so
+    /// the fit sees the same sight-in the shooter used.
+    pub fn true_trajectory(
+        &self,
+        data: &ProjectileData,
+        observations: &[Observation],
+        mode: TruingMode,
+    ) -> Result<TruingResult, BallisticsError> {
+        let parameters = mode.fits_velocity() as usize + mode.fits_bc() as usize;
+        let observations: Vec<Observation> = observations
+            .iter()
+            .filter(|o| o.range > 0.0 && (o.range - data.zero_range).abs() >= ZERO_RANGE_MARGIN)
+            .cloned()
+            .collect();
+        if observations.len() < parameters {
+            return Err(BallisticsError::Truing(format!(
+                "need at least {} observations away from the muzzle and the zero range",
+                parameters
+            )));
+        }
+
+        let residuals = |velocity: f64, bc_scale: f64| -> Result<Vec<f64>, BallisticsError> {
+            let predicted = self.predicted_drops(&scaled(data, velocity, bc_scale), &observations)?;
+            Ok(observations
+                .iter()
+                .zip(predicted.iter())
+                .map(|(o, p)| o.drop - p)
+                .collect())
+        };
+
+        let residuals_before = residuals(data.velocity, 1.0)?;
+        let mut velocity = data.velocity;
+        let mut bc_scale = 1.0;
+        let mut current = residuals_before.clone();
+
+        for _ in 0..TRUING_MAX_ITERATIONS {
+            // Columns of the Jacobian of the residuals
+            let mut columns = Vec::with_capacity(parameters);
+            if mode.fits_velocity() {
+                let shifted = residuals(velocity + VELOCITY_STEP, bc_scale)?;
+                let column = derivative(&shifted, &current, VELOCITY_STEP);
+                columns.push(sensitive(column, VELOCITY_STEP, "velocity")?);
+            }
+            if mode.fits_bc() {
+                let shifted = residuals(velocity, bc_scale + BC_SCALE_STEP)?;
+                let column = derivative(&shifted, &current, BC_SCALE_STEP);
+                columns.push(sensitive(column, BC_SCALE_STEP, "BC")?);
+            }
+
+            let step = match solve_normal_equations(&columns, &current) {
+                Some(step) => step,
+                None => break,
+            };
+
+            let mut next = step.iter();
+            let mut trial_velocity = velocity;
+            let mut trial_scale = bc_scale;
+            if mode.fits_velocity() {
+                trial_velocity -= next.next().copied().unwrap_or(0.0);
+            }
+            if mode.fits_bc() {
+                trial_scale = (trial_scale - next.next().copied().unwrap_or(0.0)).max(0.05);
+            }
+
+            let trial = residuals(trial_velocity, trial_scale)?;
+            if sum_squares(&trial) > sum_squares(&current) {
+                break;
+            }
+
+            let converged = (trial_velocity - velocity).abs() < 0.01
+                && (trial_scale - bc_scale).abs() < 1e-6;
+            velocity = trial_velocity;
+            bc_scale = trial_scale;
+            current = trial;
+            if converged {
+                break;
+            }
+        }
+
+        let trued = scaled(data, velocity, bc_scale);
+        Ok(TruingResult {
+            mode,
+            velocity,
+            bc: trued.bc,
+            bc_scale,
+            bc_segments: trued.bc_segments,
+            observations,
+            rms_before: rms(&residuals_before),
+            rms_after: rms(&current),
+            residuals_before,
+            residuals_after: current,
+        })
+    }
+
+    /// Drop (inches) at each observation's range, zeroed as `data` describes
+    /// and including aerodynamic jump, as in `calculate`.
+    fn predicted_drops(
+        &self,
+        data: &ProjectileData,
+        observations: &[Observation],
+    ) -> Result<Vec<f64>, BallisticsError> {
+        let zero_angle = self.calculate_zero_angle(data)?;
+
+        let mut order: Vec<usize> = (0..observations.len()).collect();
+        order.sort_by(|&a, &b| observations[a].range.total_cmp(&observations[b].range));
+        let ranges_feet: Vec<f64> = order.iter().map(|&i| observations[i].range * 3.0).collect();
+
+        let flight = self.flight(data);
+        let jump = Self::aerodynamic_jump(data, &flight, Self::stability(data, &flight));
+        let states = self.solve(data, &flight, zero_angle, &ranges_feet);
+        if states.len() < ranges_feet.len() {
+            return Err(BallisticsError::Truing(format!(
+                "bullet does not reach the {:.0} yard observation",
+                observations[order[states.len()]].range
+            )));
+        }
+
+        let mut drops = vec![0.0; observations.len()];
+        for (&i, state) in order.iter().zip(states.iter()) {
+            drops[i] = (state.pos.y + jump.tan() * state.pos.x) * 12.0;
+        }
+        Ok(drops)
+    }
+}
+
+/// `data` with a new muzzle velocity and every drag input scaled by `bc_scale`.
+fn scaled(data: &ProjectileData, velocity: f64, bc_scale: f64) -> ProjectileData {
+    let mut data = data.clone();
+    data.velocity = velocity;
+    data.bc *= bc_scale;
+    for segment in &mut data.bc_segments {
+        segment.bc *= bc_scale;
+    }
+    if let Some(curve) = &mut data.drag_curve {
+        for point in &mut curve.points {
+            point.1 /= bc_scale;
+        }
+    }
+    data
+}
+
+fn derivative(shifted: &[f64], base: &[f64], step: f64) -> Vec<f64> {
+    shifted.iter().zip(base).map(|(s, b)| (s - b) / step).collect()
+}
+
+/// `column` if a finite-difference `step` moves the predicted drops by more
+/// than solver noise; otherwise the observations cannot fit `parameter`.
+fn sensitive(column: Vec<f64>, step: f64, parameter: &str) -> Result<Vec<f64>, BallisticsError> {
+    if rms(&column) * step < MIN_SENSITIVITY {
+        return Err(BallisticsError::Truing(format!(
+            "observed drops do not depend on {}; add observations farther from the zero range",
+            parameter
+        )));
+    }
+    Ok(column)
+}
+
+/// Gauss-Newton step for one or two parameters: solve (JᵀJ) δ = Jᵀr.
+fn solve_normal_equations(columns: &[Vec<f64>], residuals: &[f64]) -> Option<Vec<f64>> {
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(x, y)| x * y).sum::<f64>();
+
+    match columns {
+        [a] => {
+            let aa = dot(a, a);
+            (aa > 0.0).then(|| vec![dot(a, residuals) / aa])
+        }
+        [a, b] => {
+            let (aa, ab, bb) = (dot(a, a), dot(a, b), dot(b, b));
+            let (ar, br) = (dot(a, residuals), dot(b, residuals));
+            let det = aa * bb - ab * ab;
+            (det.abs() > f64::EPSILON * aa * bb).then(|| {
+                vec![(bb * ar - ab * br) / det, (aa * br - ab * ar) / det]
+            })
+        }
+        _ => None,
+    }
+}
+
+fn sum_squares(values: &[f64]) -> f64 {
+    values.iter().map(|v| v * v).sum()
+}
+
+fn rms(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        (sum_squares(values) / values.len() as f64).sqrt()
+    }
+}
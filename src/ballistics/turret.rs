@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+const MOA_PER_RADIAN: f64 = 180.0 * 60.0 / PI;
+
+/// Angular unit a scope's turrets are graduated in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum TurretUnit {
+    #[default]
+    Moa, // true minute of angle, 1.047 in at 100 yards
+    Smoa, // shooter's MOA (IPHY), 1 in at 100 yards
+    Mil,  // milliradian, 3.6 in at 100 yards
+}
+
+impl TurretUnit {
+    pub const ALL: [TurretUnit; 3] = [TurretUnit::Moa, TurretUnit::Smoa, TurretUnit::Mil];
+
+    /// `radians` expressed in this unit.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_radians(self, radians: f64) -> f64 {
+        match self {
+            TurretUnit::Moa => radians * MOA_PER_RADIAN,
+            TurretUnit::Smoa => radians.tan() * 3600.0,
+            TurretUnit::Mil => radians * 1000.0,
+        }
+    }
+
+    /// `value` in this unit, as radians.
+    pub fn to_radians(self, value: f64) -> f64 {
+        match self {
+            TurretUnit::Moa => value / MOA_PER_RADIAN,
+            TurretUnit::Smoa => (value / 3600.0).atan(),
+            TurretUnit::Mil => value / 1000.0,
+        }
+    }
+
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            TurretUnit::Moa => "MOA",
+            TurretUnit::Smoa => "SMOA",
+            TurretUnit::Mil => "MIL",
+        }
+    }
+}
+
+impl std::fmt::Display for TurretUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+/// Elevation and windage turrets of a scope, and how it sits on the rifle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScopeTurret {
+    pub unit: TurretUnit,
+    pub click_value: f64,     // in `unit`, e.g. 0.25 MOA or 0.1 MIL
+    pub max_elevation: f64,   // total elevation travel, in `unit`
+    pub tracking_factor: f64, // measured movement ÷ dialed; 1.0 tracks true
+    pub base_cant: f64,       // MOA of elevation built into the rail or rings
+}
+
+impl Default for ScopeTurret {
+    fn default() -> Self {
+        Self {
+            unit: TurretUnit::Moa,
+            click_value: 0.25,
+            max_elevation: 60.0,
+            tracking_factor: 1.0,
+            base_cant: 0.0,
+        }
+    }
+}
+
+/// Turret clicks for one range, exact and rounded to the nearest click.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TurretClicks {
+    pub elevation: f64, // positive = up
+    pub elevation_rounded: i64,
+    pub windage: f64, // positive = right
+    pub windage_rounded: i64,
+}
+
+impl ScopeTurret {
+    /// Clicks to dial for corrections given as angles (radians, elevation
+    /// positive up, windage positive right), allowing for the tracking factor.
+    pub fn clicks(&self, elevation: f64, windage: f64) -> TurretClicks {
+        let to_clicks = |radians: f64| {
+            if self.click_value > 0.0 && self.tracking_factor > 0.0 {
+                self.unit.from_radians(radians) / self.tracking_factor / self.click_value
+            } else {
+                0.0
+            }
+        };
+        let elevation = to_clicks(elevation);
+        let windage = to_clicks(windage);
+        TurretClicks {
+            elevation,
+            elevation_rounded: elevation.round() as i64,
+            windage,
+            windage_rounded: windage.round() as i64,
+        }
+    }
+
+    /// Erector position (radians, positive = up from optical centre) needed to
+    /// hold a zero with the bore `zero_angle` above the line of sight. The base
+    /// cant supplies part of that angle.
+    pub fn zero_position(&self, zero_angle: f64) -> f64 {
+        zero_angle - TurretUnit::Moa.to_radians(self.base_cant)
+    }
+
+    /// Elevation (radians) either side of optical centre the erector can move.
+    pub fn half_travel(&self) -> f64 {
+        self.unit.to_radians(self.max_elevation / 2.0) * self.tracking_factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // One inch at 100 yards
+    fn one_inch() -> f64 {
+        (1.0_f64 / 3600.0).atan()
+    }
+
+    #[test]
+    fn clicks_for_each_unit() {
+        let clicks = |unit, click_value| {
+            ScopeTurret { unit, click_value, ..Default::default() }.clicks(one_inch(), -one_inch())
+        };
+
+        let moa = clicks(TurretUnit::Moa, 0.25);
+        // True MOA is 1.047 in at 100 yards
+        assert!((moa.elevation * 0.25 * 1.047 - 1.0).abs() < 1e-3, "{:?}", moa);
+        assert_eq!((moa.elevation_rounded, moa.windage_rounded), (4, -4));
+
+        let smoa = clicks(TurretUnit::Smoa, 0.25);
+        assert!((smoa.elevation - 4.0).abs() < 1e-9, "{:?}", smoa);
+
+        let mil = clicks(TurretUnit::Mil, 0.1);
+        assert!((mil.elevation - 1000.0 * one_inch() / 0.1).abs() < 1e-9, "{:?}", mil);
+        assert_eq!(mil.elevation_rounded, 3);
+    }
+
+    #[test]
+    fn tracking_factor_scales_clicks() {
+        let turret = ScopeTurret { tracking_factor: 1.05, ..Default::default() };
+        let exact = ScopeTurret::default().clicks(0.01, 0.0).elevation;
+        assert!((turret.clicks(0.01, 0.0).elevation - exact / 1.05).abs() < 1e-9);
+        assert!((turret.half_travel() - ScopeTurret::default().half_travel() * 1.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn units_round_trip() {
+        for unit in TurretUnit::ALL {
+            assert!((unit.to_radians(unit.from_radians(0.02)) - 0.02).abs() < 1e-12, "{}", unit);
+        }
+    }
+
+    #[test]
+    fn base_cant_takes_up_part_of_the_zero() {
+        let turret = ScopeTurret { base_cant: 20.0, ..Default::default() };
+        let zero = TurretUnit::Moa.to_radians(5.0);
+        assert!((TurretUnit::Moa.from_radians(turret.zero_position(zero)) + 15.0).abs() < 1e-9);
+    }
+}
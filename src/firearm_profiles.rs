@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::ballistics::{ScopeTurret, TruingRecord, TwistRate};
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum FirearmType {
     Rifle,
@@ -19,6 +21,10 @@ pub struct FirearmProfile {
     pub twist_rate: String,
     pub sight_height: f64,
     pub notes: String,
+    #[serde(default)]
+    pub truing: Option<TruingRecord>, // field-trued velocity and BC, if any
+    #[serde(default)]
+    pub scope: Option<ScopeTurret>,
 }
 
 impl Default for FirearmProfile {
@@ -34,6 +40,15 @@ impl Default for FirearmProfile {
             twist_rate: "1:10".to_string(),
             sight_height: 1.5,
             notes: String::new(),
+            truing: None,
+            scope: None,
         }
     }
-}
\ No newline at end of file
+}
+
+impl FirearmProfile {
+    /// Parsed `twist_rate`, e.g. "1:10" or "1:8 LH".
+    pub fn twist(&self) -> Result<TwistRate, String> {
+        self.twist_rate.parse()
+    }
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::ballistics::{BcSegment, DragModel, PowderSensitivity, TruingRecord};
+
 #[cfg(not(target_arch = "wasm32"))]
 use rusqlite::{params, Connection};
 
@@ -13,8 +15,89 @@ pub struct LoadData {
     pub bullet_weight: f64,
     pub velocity: f64,
     pub bc: f64,
+    #[serde(default)]
+    pub drag_model: DragModel,
+    #[serde(default)]
+    pub bc_segments: Vec<BcSegment>,
     pub powder_type: String,
     pub powder_charge: f64,
+    #[serde(default)]
+    pub truing: Option<TruingRecord>, // field-trued velocity and BC, if any
+    #[serde(default)]
+    pub powder_sensitivity: Option<PowderSensitivity>, // velocity change with powder temperature
+    #[serde(default)]
+    pub test_barrel_length: Option<f64>, // inches, barrel `velocity` was measured in
+    #[serde(default)]
+    pub bullet_diameter: Option<f64>, // inches
+}
+
+/// Muzzle velocity estimate for a barrel other than the load's test barrel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarrelAdjustment {
+    pub factory_velocity: f64,   // ft/s, as published for the test barrel
+    pub test_barrel_length: f64, // inches
+    pub barrel_length: f64,      // inches
+    pub fps_per_inch: f64,
+}
+
+impl BarrelAdjustment {
+    pub fn velocity_change(&self) -> f64 {
+        (self.barrel_length - self.test_barrel_length) * self.fps_per_inch
+    }
+
+    pub fn velocity(&self) -> f64 {
+        self.factory_velocity + self.velocity_change()
+    }
+}
+
+impl LoadData {
+    /// Identifies the load in the library; names are unique per manufacturer.
+    pub fn key(&self) -> String {
+        format!("{} / {}", self.manufacturer, self.name)
+    }
+
+    /// Adjustment from the load's test barrel to `barrel_length`, if the test
+    /// barrel is known.
+    pub fn barrel_adjustment(&self, barrel_length: f64) -> Option<BarrelAdjustment> {
+        let test_barrel_length = self.test_barrel_length.filter(|&l| l > 0.0)?;
+        (barrel_length > 0.0).then(|| BarrelAdjustment {
+            factory_velocity: self.velocity,
+            test_barrel_length,
+            barrel_length,
+            fps_per_inch: fps_per_inch(&self.caliber),
+        })
+    }
+}
+
+// Typical velocity change per inch of barrel, matched against the caliber
+// name in order; magnum cases gain more per inch than standard ones
+const BARREL_FPS_PER_INCH: &[(&str, f64)] = &[
+    (".22 Long Rifle", 5.0),
+    (".22 Winchester Magnum", 15.0),
+    (".17 HMR", 15.0),
+    ("Magnum", 30.0),
+    ("PRC", 30.0),
+    ("Creedmoor", 25.0),
+    (".223", 25.0),
+    ("5.56", 25.0),
+    (".243", 25.0),
+    (".270", 25.0),
+    (".308", 20.0),
+    ("30-06", 20.0),
+    ("9mm", 20.0),
+    (".40 S&W", 15.0),
+    (".380", 15.0),
+    (".38 Special", 15.0),
+    (".45 ACP", 10.0),
+];
+const DEFAULT_FPS_PER_INCH: f64 = 25.0;
+
+/// Muzzle velocity change (ft/s) per inch of barrel length for `caliber`.
+pub fn fps_per_inch(caliber: &str) -> f64 {
+    BARREL_FPS_PER_INCH
+        .iter()
+        .find(|(name, _)| caliber.contains(name))
+        .map_or(DEFAULT_FPS_PER_INCH, |&(_, fps)| fps)
 }
 
 pub struct LoadDataLibrary {
@@ -62,6 +145,8 @@ impl LoadDataLibrary {
                 // Check if tables exist, if not, initialize
                 if !self.tables_exist(&conn) {
                     self.create_and_populate_database(&conn);
+                } else {
+                    self.migrate_schema(&conn);
                 }
                 self.db_connection = Some(conn);
             }
@@ -92,6 +177,56 @@ impl LoadDataLibrary {
         }
     }
     
+    #[cfg(not(target_arch = "wasm32"))]
+    fn column_exists(&self, conn: &Connection, column: &str) -> bool {
+        let query = "SELECT 1 FROM pragma_table_info('load_data') WHERE name = ?1";
+        conn.query_row(query, [column], |_| Ok(())).is_ok()
+    }
+    
+    // Bring databases created by older versions up to the current schema
+    #[cfg(not(target_arch = "wasm32"))]
+    fn migrate_schema(&self, conn: &Connection) {
+        if let Err(e) = conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS load_bc_segments (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                load_id INTEGER NOT NULL,
+                velocity REAL NOT NULL,
+                bc REAL NOT NULL,
+                FOREIGN KEY (load_id) REFERENCES load_data(id),
+                UNIQUE(load_id, velocity)
+            );",
+        ) {
+            eprintln!("Failed to migrate database: {}", e);
+        }
+
+        let columns = [
+            ("drag_model", "ALTER TABLE load_data ADD COLUMN drag_model TEXT NOT NULL DEFAULT 'G1'"),
+            ("temp_sensitivity", "ALTER TABLE load_data ADD COLUMN temp_sensitivity REAL"),
+            ("reference_temperature", "ALTER TABLE load_data ADD COLUMN reference_temperature REAL"),
+            ("test_barrel_length", "ALTER TABLE load_data ADD COLUMN test_barrel_length REAL"),
+            ("bullet_diameter", "ALTER TABLE load_data ADD COLUMN bullet_diameter REAL"),
+        ];
+        for (column, sql) in columns {
+            if !self.column_exists(conn, column) {
+                if let Err(e) = conn.execute(sql, []) {
+                    eprintln!("Failed to migrate database: {}", e);
+                }
+            }
+        }
+
+        // Each script only fills in what is missing, so rerunning them is safe
+        for sql in [
+            include_str!("../migrations/bc_segments.sql"),
+            include_str!("../migrations/test_barrels.sql"),
+            include_str!("../migrations/bullet_diameters.sql"),
+            include_str!("../migrations/powder_sensitivity.sql"),
+        ] {
+            if let Err(e) = conn.execute_batch(sql) {
+                eprintln!("Failed to migrate database: {}", e);
+            }
+        }
+    }
+    
     #[cfg(not(target_arch = "wasm32"))]
     fn create_and_populate_database(&self, conn: &Connection) {
         // Read and execute the SQL initialization script
@@ -99,6 +234,16 @@ impl LoadDataLibrary {
         if let Err(e) = conn.execute_batch(sql) {
             eprintln!("Failed to initialize database: {}", e);
         }
+        for sql in [
+            include_str!("../migrations/bc_segments.sql"),
+            include_str!("../migrations/test_barrels.sql"),
+            include_str!("../migrations/bullet_diameters.sql"),
+            include_str!("../migrations/powder_sensitivity.sql"),
+        ] {
+            if let Err(e) = conn.execute_batch(sql) {
+                eprintln!("Failed to initialize database: {}", e);
+            }
+        }
     }
     
    #[cfg(not(target_arch = "wasm32"))]
@@ -111,7 +256,11 @@ fn load_from_database(&mut self) {
     
     let query = "
         SELECT m.name, l.name, l.caliber, l.bullet_weight, 
-               l.velocity, l.bc, l.powder_type, l.powder_charge
+               l.velocity, l.bc, l.drag_model, l.powder_type, l.powder_charge,
+               (SELECT GROUP_CONCAT(s.velocity || ':' || s.bc, ';')
+                FROM load_bc_segments s WHERE s.load_id = l.id),
+               l.temp_sensitivity, l.reference_temperature, l.test_barrel_length,
+               l.bullet_diameter
         FROM load_data l
         JOIN manufacturers m ON l.manufacturer_id = m.id
         ORDER BY m.name, l.caliber, l.bullet_weight
@@ -129,8 +278,20 @@ fn load_from_database(&mut self) {
                     bullet_weight: row.get(3)?,
                     velocity: row.get(4)?,
                     bc: row.get(5)?,
-                    powder_type: row.get(6)?,
-                    powder_charge: row.get(7)?,
+                    drag_model: row
+                        .get::<_, String>(6)?
+                        .parse()
+                        .unwrap_or_default(),
+                    bc_segments: row
+                        .get::<_, Option<String>>(9)?
+                        .map(|text| BcSegment::parse_text(&text))
+                        .unwrap_or_default(),
+                    powder_type: row.get(7)?,
+                    powder_charge: row.get(8)?,
+                    truing: None,
+                    powder_sensitivity: powder_sensitivity(row.get(10)?, row.get(11)?),
+                    test_barrel_length: row.get(12)?,
+                    bullet_diameter: row.get(13)?,
                 })
             });
             
@@ -171,8 +332,22 @@ fn load_from_database(&mut self) {
                     bullet_weight: 175.0,
                     velocity: 2600.0,
                     bc: 0.505,
+                    drag_model: DragModel::G1,
+                    bc_segments: vec![
+                        BcSegment { velocity: 2800.0, bc: 0.505 },
+                        BcSegment { velocity: 1800.0, bc: 0.496 },
+                        BcSegment { velocity: 0.0, bc: 0.485 },
+                    ],
                     powder_type: "IMR 4064".to_string(),
                     powder_charge: 42.5,
+                    truing: None,
+                    powder_sensitivity: Some(PowderSensitivity {
+                        fps_per_degree: 1.0,
+                        reference_temperature: 70.0,
+                        powder_temperature: None,
+                    }),
+                    test_barrel_length: Some(24.0),
+                    bullet_diameter: Some(0.308),
                 },
                 LoadData {
                     manufacturer: "Federal".to_string(),
@@ -181,8 +356,18 @@ fn load_from_database(&mut self) {
                     bullet_weight: 140.0,
                     velocity: 2750.0,
                     bc: 0.610,
+                    drag_model: DragModel::G1,
+                    bc_segments: Vec::new(),
                     powder_type: "H4350".to_string(),
                     powder_charge: 41.5,
+                    truing: None,
+                    powder_sensitivity: Some(PowderSensitivity {
+                        fps_per_degree: 0.3,
+                        reference_temperature: 70.0,
+                        powder_temperature: None,
+                    }),
+                    test_barrel_length: Some(24.0),
+                    bullet_diameter: Some(0.264),
                 },
             ],
         );
@@ -197,8 +382,18 @@ fn load_from_database(&mut self) {
                     bullet_weight: 147.0,
                     velocity: 2695.0,
                     bc: 0.697,
+                    drag_model: DragModel::G1,
+                    bc_segments: Vec::new(),
                     powder_type: "H4350".to_string(),
                     powder_charge: 40.8,
+                    truing: None,
+                    powder_sensitivity: Some(PowderSensitivity {
+                        fps_per_degree: 0.3,
+                        reference_temperature: 70.0,
+                        powder_temperature: None,
+                    }),
+                    test_barrel_length: Some(24.0),
+                    bullet_diameter: Some(0.264),
                 },
             ],
         );
@@ -215,8 +410,14 @@ fn load_from_database(&mut self) {
                 bullet_weight: 168.0,
                 velocity: 2650.0,
                 bc: 0.462,
+                drag_model: DragModel::G1,
+                bc_segments: Vec::new(),
                 powder_type: "Varget".to_string(),
                 powder_charge: 44.5,
+                truing: None,
+                powder_sensitivity: None,
+                test_barrel_length: None,
+                bullet_diameter: Some(0.308),
             },
             LoadData {
                 manufacturer: "Custom".to_string(),
@@ -225,8 +426,14 @@ fn load_from_database(&mut self) {
                 bullet_weight: 143.0,
                 velocity: 2700.0,
                 bc: 0.625,
+                drag_model: DragModel::G1,
+                bc_segments: Vec::new(),
                 powder_type: "H4350".to_string(),
                 powder_charge: 40.5,
+                truing: None,
+                powder_sensitivity: None,
+                test_barrel_length: None,
+                bullet_diameter: Some(0.264),
             },
         ];
         
@@ -253,12 +460,18 @@ fn load_from_database(&mut self) {
             .collect()
     }
     
+    /// Add `load` to the Custom library, replacing any load with the same key.
     pub fn add_custom_load(&mut self, load: LoadData) {
-        self.custom_loads.push(load.clone());
-        self.loads
-            .entry("Custom".to_string())
-            .or_insert_with(Vec::new)
-            .push(load);
+        match self.custom_loads.iter_mut().find(|l| l.key() == load.key()) {
+            Some(existing) => *existing = load,
+            None => self.custom_loads.push(load),
+        }
+        self.loads.insert("Custom".to_string(), self.custom_loads.clone());
+    }
+
+    /// Drop user-saved loads, leaving the built-in custom examples.
+    pub fn reset_custom_loads(&mut self) {
+        self.load_custom_loads();
     }
     
     pub fn remove_custom_load(&mut self, index: usize) {
@@ -275,7 +488,11 @@ pub fn search_loads(&self, query: &str) -> Vec<LoadData> {
     if let Some(ref conn) = self.db_connection {
         let sql = "
             SELECT m.name, l.name, l.caliber, l.bullet_weight, 
-                   l.velocity, l.bc, l.powder_type, l.powder_charge
+                   l.velocity, l.bc, l.drag_model, l.powder_type, l.powder_charge,
+               (SELECT GROUP_CONCAT(s.velocity || ':' || s.bc, ';')
+                FROM load_bc_segments s WHERE s.load_id = l.id),
+               l.temp_sensitivity, l.reference_temperature, l.test_barrel_length,
+               l.bullet_diameter
             FROM load_data l
             JOIN manufacturers m ON l.manufacturer_id = m.id
             WHERE l.name LIKE ?1 OR l.caliber LIKE ?1 OR m.name LIKE ?1
@@ -293,8 +510,20 @@ pub fn search_loads(&self, query: &str) -> Vec<LoadData> {
                     bullet_weight: row.get(3)?,
                     velocity: row.get(4)?,
                     bc: row.get(5)?,
-                    powder_type: row.get(6)?,
-                    powder_charge: row.get(7)?,
+                    drag_model: row
+                        .get::<_, String>(6)?
+                        .parse()
+                        .unwrap_or_default(),
+                    bc_segments: row
+                        .get::<_, Option<String>>(9)?
+                        .map(|text| BcSegment::parse_text(&text))
+                        .unwrap_or_default(),
+                    powder_type: row.get(7)?,
+                    powder_charge: row.get(8)?,
+                    truing: None,
+                    powder_sensitivity: powder_sensitivity(row.get(10)?, row.get(11)?),
+                    test_barrel_length: row.get(12)?,
+                    bullet_diameter: row.get(13)?,
                 })
             }) {
                 for load in load_iter.flatten() {
@@ -312,4 +541,73 @@ impl Default for LoadDataLibrary {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+// Loads without a sensitivity coefficient leave the velocity unadjusted
+#[cfg(not(target_arch = "wasm32"))]
+fn powder_sensitivity(
+    fps_per_degree: Option<f64>,
+    reference_temperature: Option<f64>,
+) -> Option<PowderSensitivity> {
+    fps_per_degree.map(|fps_per_degree| PowderSensitivity {
+        fps_per_degree,
+        reference_temperature: reference_temperature
+            .unwrap_or(PowderSensitivity::default().reference_temperature),
+        powder_temperature: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(caliber: &str, test_barrel_length: Option<f64>) -> LoadData {
+        LoadData {
+            manufacturer: "Federal".to_string(),
+            name: "Gold Medal Match".to_string(),
+            caliber: caliber.to_string(),
+            bullet_weight: 175.0,
+            velocity: 2600.0,
+            bc: 0.505,
+            drag_model: DragModel::G1,
+            bc_segments: Vec::new(),
+            powder_type: "Unknown".to_string(),
+            powder_charge: 0.0,
+            truing: None,
+            powder_sensitivity: None,
+            test_barrel_length,
+            bullet_diameter: Some(0.308),
+        }
+    }
+
+    #[test]
+    fn fps_per_inch_matches_the_caliber_name() {
+        assert_eq!(fps_per_inch(".308 Winchester"), 20.0);
+        assert_eq!(fps_per_inch("6.5 Creedmoor"), 25.0);
+        assert_eq!(fps_per_inch(".300 Winchester Magnum"), 30.0);
+        // Rimfire magnum is matched before the generic magnum entry
+        assert_eq!(fps_per_inch(".22 Winchester Magnum"), 15.0);
+        assert_eq!(fps_per_inch("7mm-08 Remington"), DEFAULT_FPS_PER_INCH);
+    }
+
+    #[test]
+    fn barrel_adjustment_scales_with_length_difference() {
+        let adjustment = load(".308 Winchester", Some(24.0)).barrel_adjustment(20.0).unwrap();
+        assert_eq!(adjustment.fps_per_inch, 20.0);
+        assert_eq!(adjustment.velocity_change(), -80.0);
+        assert_eq!(adjustment.velocity(), 2520.0);
+
+        let longer = load(".308 Winchester", Some(24.0)).barrel_adjustment(26.0).unwrap();
+        assert_eq!(longer.velocity(), 2640.0);
+
+        // Unlisted calibers fall back to the default rate
+        let other = load("7mm-08 Remington", Some(24.0)).barrel_adjustment(22.0).unwrap();
+        assert_eq!(other.velocity(), 2600.0 - 2.0 * DEFAULT_FPS_PER_INCH);
+    }
+
+    #[test]
+    fn barrel_adjustment_needs_both_barrel_lengths() {
+        assert!(load(".308 Winchester", None).barrel_adjustment(20.0).is_none());
+        assert!(load(".308 Winchester", Some(0.0)).barrel_adjustment(20.0).is_none());
+        assert!(load(".308 Winchester", Some(24.0)).barrel_adjustment(0.0).is_none());
+    }
+}
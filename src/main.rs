@@ -4,6 +4,7 @@
 use chrono::Utc;
 use eframe::egui;
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 mod auth;
@@ -19,13 +20,20 @@ mod sharing;
 mod pwa;
 
 use auth::NostrAuth;
-use ballistics::{BallisticsCalculator, ProjectileData, TrajectoryResult};
+use ballistics::{
+    BallisticsCalculator, BallisticsError, BcSegment, DispersionResult, DragCurve, DragModel, GameClass, MaximumRange, MovingTarget,
+    Observation, PointBlankRange, PowderSensitivity, PressureKind, ProjectileData, RangeSpec, RangeTable, RangeUnit, TargetShape,
+    ScopeTurret, TrajectoryResult, TruingMode, TruingRecord, TruingResult, TurretUnit, TwistDirection, TwistRate,
+    Uncertainty, WindZone, ZeroConditions, WIND_BRACKET_SPEEDS,
+};
 use firearm_profiles::{FirearmProfile, FirearmType};
 use hardware::{HardwareManager, RangefinderData, WeatherData};
-use load_data::LoadDataLibrary;
+use load_data::{BarrelAdjustment, LoadDataLibrary};
 use sharing::SharingManager;
 use storage::LocalStorage;
 
+// The web build runs dispersion on the UI thread, so keep its runs short
+const MAX_DISPERSION_SAMPLES: usize = if cfg!(target_arch = "wasm32") { 500 } else { 20000 };
 
 // Define AttachedImage locally
 #[derive(Clone)]
@@ -49,8 +57,22 @@ pub struct BallisticsApp {
     current_screen: Screen,
     current_calculation: CalculationData,
     trajectory_results: Option<TrajectoryResult>,
+    uncertainty: Uncertainty,
+    target_shape: TargetShape,
+    dispersion_results: Option<DispersionResult>,
+    dispersion_job: Option<Arc<Mutex<Option<Result<DispersionResult, BallisticsError>>>>>, // Monte Carlo run in progress
+    observations: Vec<Observation>,
+    truing_mode: TruingMode,
+    truing_result: Option<TruingResult>,
+    vital_zone: f64,
+    point_blank: Option<PointBlankRange>,
+    maximum_range: Option<MaximumRange>,
     firearm_profiles: Vec<FirearmProfile>,
     selected_profile: Option<usize>,
+    applied_load: Option<load_data::LoadData>,
+    barrel_adjustment: Option<BarrelAdjustment>,
+    drag_curves: Vec<DragCurve>,
+    pending_drag_curve: Option<DragCurve>, // imported, waiting for its reference diameter
     show_load_library: bool,
     show_hardware_panel: bool,
     calculation_history: Vec<SavedCalculation>,
@@ -160,6 +182,7 @@ impl BallisticsApp {
             },
             key_input_buffer: String::new(),
             load_library: LoadDataLibrary::new(),
+            vital_zone: 8.0,
             ..Default::default()
         };
 
@@ -192,6 +215,8 @@ impl eframe::App for BallisticsApp {
             egui::Visuals::light()
         });
 
+        self.poll_dispersion(ctx);
+
         // Top menu bar
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             ui.horizontal(|ui| {
@@ -228,6 +253,9 @@ impl eframe::App for BallisticsApp {
                         if let Some(data) = self.hardware.get_rangefinder_data() {
                             ui.label(format!("Distance: {} yards", data.distance));
                             ui.label(format!("Angle: {}°", data.angle));
+                            if ui.button("📥 Use Angle").clicked() {
+                                self.apply_range_data(data);
+                            }
                         }
                     } else {
                         if ui.button("Connect Rangefinder").clicked() {
@@ -262,6 +290,46 @@ impl eframe::App for BallisticsApp {
             });
         }
 
+        // Imported drag curve waiting for its reference diameter
+        if let Some(mut curve) = self.pending_drag_curve.take() {
+            let mut keep = true;
+            let mut save = false;
+            egui::Window::new("📐 Drag Curve Diameter")
+                .collapsible(false)
+                .resizable(false)
+                .show(ctx, |ui| {
+                    ui.label(format!(
+                        "\"{}\" does not give the reference diameter its Cd was measured against.",
+                        curve.name
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.label("Diameter:");
+                        ui.add(
+                            egui::DragValue::new(&mut curve.diameter)
+                                .speed(0.001)
+                                .range(0.0..=1.0)
+                                .suffix(" in"),
+                        );
+                    });
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(curve.diameter > 0.0, egui::Button::new("✅ Save"))
+                            .clicked()
+                        {
+                            save = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            keep = false;
+                        }
+                    });
+                });
+            if save {
+                self.add_drag_curve(curve);
+            } else if keep {
+                self.pending_drag_curve = Some(curve);
+            }
+        }
+
         // Main content area
         egui::CentralPanel::default().show(ctx, |ui| {
             match self.current_screen {
@@ -517,6 +585,7 @@ impl BallisticsApp {
     .show(ui, |ui| {
             self.show_projectile_data_section(ui);
             self.show_environmental_conditions_section(ui);
+            self.show_range_table_section(ui);
             self.show_notes_photos_section(ui);
         });
 
@@ -542,6 +611,410 @@ impl BallisticsApp {
             ui.separator();
             self.show_trajectory_results(ui, results);
         }
+
+        if self.trajectory_results.is_some() {
+            ui.separator();
+            self.show_dispersion_section(ui);
+        }
+
+        ui.separator();
+        self.show_point_blank_section(ui);
+
+        ui.separator();
+        self.show_maximum_range_section(ui);
+
+        ui.separator();
+        self.show_truing_section(ui);
+    }
+
+    fn show_point_blank_section(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("🦌 Maximum Point-Blank Range")
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Vital Zone:");
+                    ui.add(
+                        egui::DragValue::new(&mut self.vital_zone)
+                            .speed(0.5)
+                            .range(1.0..=48.0)
+                            .suffix(" in"),
+                    );
+                    if ui.button("▶ Find MPBR").clicked() {
+                        match self
+                            .calculator()
+                            .point_blank_range(&self.current_calculation.projectile_data, self.vital_zone)
+                        {
+                            Ok(result) => self.point_blank = Some(result),
+                            Err(e) => self.error_message = Some(e.to_string()),
+                        }
+                    }
+                });
+
+                let Some(result) = self.point_blank.clone() else {
+                    return;
+                };
+                ui.label(format!(
+                    "Within ±{:.1}\" from {:.0} to {:.0} yds (near zero {:.0} yds)",
+                    result.vital_zone / 2.0,
+                    result.near_limit,
+                    result.far_limit,
+                    result.near_zero
+                ));
+                ui.label(format!(
+                    "Max ordinate {:.1}\" at {:.0} yds",
+                    result.max_ordinate, result.max_ordinate_range
+                ));
+                ui.horizontal(|ui| {
+                    ui.label(format!("Zero at {:.0} yds", result.zero_range));
+                    if ui.button("✅ Use This Zero").clicked() {
+                        self.current_calculation.projectile_data.zero_range = result.zero_range.round();
+                    }
+                });
+            });
+    }
+
+    fn show_maximum_range_section(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("🚧 Maximum Range (Surface Danger Zone)")
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.label("Furthest the bullet can travel over level ground, at the best launch angle:");
+                if ui.button("▶ Find Maximum Range").clicked() {
+                    match self
+                        .calculator()
+                        .maximum_range(&self.current_calculation.projectile_data)
+                    {
+                        Ok(result) => self.maximum_range = Some(result),
+                        Err(e) => self.error_message = Some(e.to_string()),
+                    }
+                }
+
+                let Some(result) = self.maximum_range.clone() else {
+                    return;
+                };
+                ui.label(format!(
+                    "Maximum range {:.0} yds at {:.1}° elevation",
+                    result.distance, result.angle
+                ));
+                ui.label(format!(
+                    "Time of flight {:.1} s, apex {:.0} ft above the muzzle",
+                    result.time, result.apex
+                ));
+                ui.label(format!(
+                    "Impact at {:.0} fps, {:.0}° below horizontal",
+                    result.impact_velocity, result.impact_angle
+                ));
+                if result.drift.abs() >= 1.0 {
+                    ui.label(format!(
+                        "Wind carries it {:.0} yds {}",
+                        result.drift.abs(),
+                        if result.drift > 0.0 { "right" } else { "left" }
+                    ));
+                }
+            });
+    }
+
+    fn show_truing_section(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("🔧 True to Observed Drops")
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.label("Measured drops relative to the line of sight (negative = low):");
+                let mut to_remove = None;
+                for (i, observation) in self.observations.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut observation.range)
+                                .speed(5.0)
+                                .range(0.0..=5000.0)
+                                .suffix(" yds"),
+                        );
+                        ui.add(egui::DragValue::new(&mut observation.drop).speed(0.1).suffix(" in"));
+                        if ui.small_button("❌").clicked() {
+                            to_remove = Some(i);
+                        }
+                    });
+                }
+                if let Some(i) = to_remove {
+                    self.observations.remove(i);
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.button("➕ Add Observation").clicked() {
+                        let range = self.observations.last().map_or(300.0, |o| o.range + 100.0);
+                        self.observations.push(Observation { range, drop: 0.0 });
+                    }
+                    ui.separator();
+                    ui.label("Fit:");
+                    ui.selectable_value(&mut self.truing_mode, TruingMode::Velocity, "Velocity");
+                    ui.selectable_value(&mut self.truing_mode, TruingMode::Bc, "BC");
+                    ui.selectable_value(&mut self.truing_mode, TruingMode::Both, "Both");
+                    if ui.button("▶ True").clicked() {
+                        match self.calculator().true_trajectory(
+                            &self.current_calculation.projectile_data,
+                            &self.observations,
+                            self.truing_mode,
+                        ) {
+                            Ok(result) => self.truing_result = Some(result),
+                            Err(e) => self.error_message = Some(e.to_string()),
+                        }
+                    }
+                });
+
+                let Some(result) = self.truing_result.clone() else {
+                    return;
+                };
+                let data = &self.current_calculation.projectile_data;
+                ui.label(format!(
+                    "Velocity: {:.0} → {:.0} fps   BC: {:.3} → {:.3}   RMS: {:.2}\" → {:.2}\"",
+                    data.velocity,
+                    result.velocity,
+                    data.bc,
+                    result.bc,
+                    result.rms_before,
+                    result.rms_after
+                ));
+
+                egui::Grid::new("truing_grid")
+                    .striped(true)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.strong("Range");
+                        ui.strong("Observed");
+                        ui.strong("Residual Before");
+                        ui.strong("Residual After");
+                        ui.end_row();
+                        for ((observation, before), after) in result
+                            .observations
+                            .iter()
+                            .zip(&result.residuals_before)
+                            .zip(&result.residuals_after)
+                        {
+                            ui.label(format!("{:.0} yds", observation.range));
+                            ui.label(format!("{:.1}\"", observation.drop));
+                            ui.label(format!("{:+.2}\"", before));
+                            ui.label(format!("{:+.2}\"", after));
+                            ui.end_row();
+                        }
+                    });
+
+                ui.horizontal(|ui| {
+                    if ui.button("✅ Apply").clicked() {
+                        result.apply(&mut self.current_calculation.projectile_data);
+                    }
+                    if let Some(index) = self.selected_profile {
+                        if ui.button("💾 Save to Profile").clicked() {
+                            let record = result.record(
+                                &self.current_calculation.projectile_data,
+                                self.applied_load.as_ref().map(|load| load.key()),
+                                Utc::now().to_rfc3339(),
+                            );
+                            if let Some(profile) = self.firearm_profiles.get_mut(index) {
+                                profile.truing = Some(record);
+                            }
+                            self.save_profiles();
+                            self.error_message = Some("Trued values saved to profile".to_string());
+                        }
+                    }
+                    if ui.button("📚 Save as Custom Load").clicked() {
+                        self.save_trued_load(&result);
+                    }
+                });
+            });
+    }
+
+    // Pick up a finished background dispersion run, whether or not its
+    // section is showing
+    fn poll_dispersion(&mut self, ctx: &egui::Context) {
+        let Some(job) = &self.dispersion_job else {
+            return;
+        };
+        // Checked first, so a worker that stores its result and exits
+        // in between is not mistaken for one that died
+        let worker_gone = Arc::strong_count(job) == 1;
+        let finished = job.lock().unwrap_or_else(|e| e.into_inner()).take();
+        match finished {
+            Some(Ok(results)) => {
+                self.dispersion_results = Some(results);
+                self.dispersion_job = None;
+            }
+            Some(Err(e)) => {
+                self.error_message = Some(e.to_string());
+                self.dispersion_job = None;
+            }
+            // The worker dropped its handle without a result, so it panicked
+            None if worker_gone => {
+                self.error_message = Some("Dispersion run failed".to_string());
+                self.dispersion_job = None;
+            }
+            None => ctx.request_repaint_after(std::time::Duration::from_millis(100)),
+        }
+    }
+
+    fn show_dispersion_section(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("🎲 Hit Probability")
+            .default_open(false)
+            .show(ui, |ui| {
+                let u = &mut self.uncertainty;
+                ui.horizontal(|ui| {
+                    ui.label("MV SD:");
+                    ui.add(
+                        egui::DragValue::new(&mut u.velocity_sd)
+                            .speed(0.5)
+                            .range(0.0..=200.0)
+                            .suffix(" fps"),
+                    );
+                    ui.label("BC SD:");
+                    let mut bc_percent = u.bc_sd * 100.0;
+                    let bc_field = egui::DragValue::new(&mut bc_percent)
+                        .speed(0.1)
+                        .range(0.0..=50.0)
+                        .suffix(" %");
+                    if ui.add(bc_field).changed() {
+                        u.bc_sd = bc_percent / 100.0;
+                    }
+                    ui.label("Range SD:");
+                    ui.add(
+                        egui::DragValue::new(&mut u.range_sd)
+                            .speed(0.5)
+                            .range(0.0..=200.0)
+                            .suffix(" yds"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Wind SD:");
+                    ui.add(
+                        egui::DragValue::new(&mut u.wind_speed_sd)
+                            .speed(0.1)
+                            .range(0.0..=30.0)
+                            .suffix(" mph"),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut u.wind_angle_sd)
+                            .speed(1.0)
+                            .range(0.0..=180.0)
+                            .suffix(" °"),
+                    );
+                    ui.label("Precision:");
+                    ui.add(
+                        egui::DragValue::new(&mut u.precision_moa)
+                            .speed(0.05)
+                            .range(0.0..=10.0)
+                            .suffix(" MOA"),
+                    );
+                    ui.label("Shots:");
+                    ui.add(
+                        egui::DragValue::new(&mut u.samples)
+                            .speed(10.0)
+                            .range(10..=MAX_DISPERSION_SAMPLES),
+                    );
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Target:");
+                    let is_circle = matches!(self.target_shape, TargetShape::Circle { .. });
+                    if ui.selectable_label(is_circle, "Circle").clicked() && !is_circle {
+                        self.target_shape = TargetShape::Circle { diameter: 10.0 };
+                    }
+                    if ui.selectable_label(!is_circle, "Rectangle").clicked() && is_circle {
+                        self.target_shape = TargetShape::Rectangle { width: 12.0, height: 18.0 };
+                    }
+                    match &mut self.target_shape {
+                        TargetShape::Circle { diameter } => {
+                            ui.add(
+                                egui::DragValue::new(diameter)
+                                    .speed(0.5)
+                                    .range(0.5..=120.0)
+                                    .suffix(" in"),
+                            );
+                        }
+                        TargetShape::Rectangle { width, height } => {
+                            ui.add(
+                                egui::DragValue::new(width)
+                                    .speed(0.5)
+                                    .range(0.5..=120.0)
+                                    .suffix(" in"),
+                            );
+                            ui.label("x");
+                            ui.add(
+                                egui::DragValue::new(height)
+                                    .speed(0.5)
+                                    .range(0.5..=120.0)
+                                    .suffix(" in"),
+                            );
+                        }
+                    }
+                    let running = self.dispersion_job.is_some();
+                    if ui.add_enabled(!running, egui::Button::new("▶ Run")).clicked() {
+                        self.run_dispersion();
+                    }
+                    if running {
+                        ui.spinner();
+                    }
+                });
+
+                if let Some(results) = &self.dispersion_results {
+                    ui.label(format!("{} shots on a {}", results.samples, results.target));
+                    egui::Grid::new("dispersion_grid")
+                        .striped(true)
+                        .spacing([10.0, 4.0])
+                        .show(ui, |ui| {
+                            ui.strong("Range");
+                            ui.strong("Vertical SD");
+                            ui.strong("Horizontal SD");
+                            ui.strong("Center");
+                            ui.strong("Hit %");
+                            ui.end_row();
+
+                            for point in &results.points {
+                                let unit = results.range_unit.abbreviation();
+                                ui.label(format!("{:.0} {}", point.distance, unit));
+                                ui.label(format!("{:.1}\"", point.vertical_sd));
+                                ui.label(format!("{:.1}\"", point.horizontal_sd));
+                                ui.label(format!(
+                                    "{:+.1}\", {:+.1}\"",
+                                    point.horizontal_mean, point.vertical_mean
+                                ));
+                                let color = match point.hit_probability {
+                                    p if p >= 0.9 => egui::Color32::GREEN,
+                                    p if p >= 0.5 => egui::Color32::from_rgb(255, 200, 100),
+                                    _ => egui::Color32::from_rgb(255, 120, 100),
+                                };
+                                ui.colored_label(color, format!("{:.0}%", point.hit_probability * 100.0));
+                                ui.end_row();
+                            }
+                        });
+                }
+            });
+    }
+
+    // Every shot is a full flight, so the run goes on a worker thread like the
+    // hardware readers and `update` polls for the result
+    fn run_dispersion(&mut self) {
+        let calculator = self.calculator();
+        let data = self.current_calculation.projectile_data.clone();
+        let mut uncertainty = self.uncertainty.clone();
+        uncertainty.samples = uncertainty.samples.min(MAX_DISPERSION_SAMPLES);
+        let target = self.target_shape;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let job = Arc::new(Mutex::new(None));
+            let slot = job.clone();
+            std::thread::spawn(move || {
+                let result = calculator.dispersion(&data, &uncertainty, &target);
+                *slot.lock().unwrap_or_else(|e| e.into_inner()) = Some(result);
+            });
+            self.dispersion_job = Some(job);
+        }
+
+        // No threads on the web; the run blocks until it finishes, which the
+        // sample cap keeps brief
+        #[cfg(target_arch = "wasm32")]
+        {
+            match calculator.dispersion(&data, &uncertainty, &target) {
+                Ok(results) => self.dispersion_results = Some(results),
+                Err(e) => self.error_message = Some(e.to_string()),
+            }
+        }
     }
 
     fn show_projectile_data_section(&mut self, ui: &mut egui::Ui) {
@@ -570,6 +1043,138 @@ impl BallisticsApp {
                                     .suffix(" fps"),
                             );
                         });
+                        let mut clear_adjustment = false;
+                        if let Some(adjustment) = &mut self.barrel_adjustment {
+                            let velocity = &mut self.current_calculation.projectile_data.velocity;
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    "Barrel: {:.1}\" test → {:.1}\" at",
+                                    adjustment.test_barrel_length, adjustment.barrel_length
+                                ));
+                                if ui
+                                    .add(
+                                        egui::DragValue::new(&mut adjustment.fps_per_inch)
+                                            .speed(0.5)
+                                            .range(0.0..=100.0)
+                                            .suffix(" fps/in"),
+                                    )
+                                    .changed()
+                                {
+                                    *velocity = adjustment.velocity();
+                                }
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    "Estimated MV: {:.0} fps ({:+.0} from {:.0} factory)",
+                                    adjustment.velocity(),
+                                    adjustment.velocity_change(),
+                                    adjustment.factory_velocity
+                                ));
+                                if (*velocity - adjustment.velocity()).abs() > 0.5 {
+                                    ui.weak("overridden");
+                                }
+                                if ui
+                                    .small_button("Use Factory")
+                                    .on_hover_text("Ignore the barrel length adjustment")
+                                    .clicked()
+                                {
+                                    *velocity = adjustment.factory_velocity;
+                                    clear_adjustment = true;
+                                }
+                            });
+                        }
+                        if clear_adjustment {
+                            self.barrel_adjustment = None;
+                        }
+                        let air_temperature = self.current_calculation.projectile_data.temperature;
+                        let powder = &mut self.current_calculation.projectile_data.powder;
+                        ui.horizontal(|ui| {
+                            let mut has_powder = powder.is_some();
+                            if ui
+                                .checkbox(&mut has_powder, "Temp Sensitivity:")
+                                .on_hover_text("Muzzle velocity change per degree of powder temperature")
+                                .changed()
+                            {
+                                *powder = has_powder.then(PowderSensitivity::default);
+                            }
+                            if let Some(powder) = powder.as_mut() {
+                                ui.add(
+                                    egui::DragValue::new(&mut powder.fps_per_degree)
+                                        .speed(0.05)
+                                        .range(-10.0..=10.0)
+                                        .suffix(" fps/°F"),
+                                );
+                            }
+                        });
+                        if let Some(powder) = powder.as_mut() {
+                            ui.horizontal(|ui| {
+                                ui.label("Velocity Measured At:");
+                                ui.add(
+                                    egui::DragValue::new(&mut powder.reference_temperature)
+                                        .speed(0.5)
+                                        .range(-40.0..=140.0)
+                                        .suffix("°F"),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                let mut separate = powder.powder_temperature.is_some();
+                                if ui
+                                    .checkbox(&mut separate, "Powder Temp:")
+                                    .on_hover_text("Leave unchecked to use the air temperature")
+                                    .changed()
+                                {
+                                    powder.powder_temperature = separate.then_some(air_temperature);
+                                }
+                                if let Some(temperature) = powder.powder_temperature.as_mut() {
+                                    ui.add(
+                                        egui::DragValue::new(temperature)
+                                            .speed(0.5)
+                                            .range(-40.0..=160.0)
+                                            .suffix("°F"),
+                                    );
+                                }
+                            });
+                            let velocity = self.current_calculation.projectile_data.velocity;
+                            ui.label(format!(
+                                "Adjusted MV: {:.0} fps",
+                                powder.adjust(velocity, air_temperature)
+                            ));
+                        }
+                        ui.horizontal(|ui| {
+                            ui.label("Bullet Length:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.bullet_length)
+                                    .speed(0.01)
+                                    .range(0.0..=5.0)
+                                    .suffix(" in"),
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Bullet Diameter:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.bullet_diameter)
+                                    .speed(0.001)
+                                    .range(0.0..=1.0)
+                                    .suffix(" in"),
+                            );
+                        });
+                        let twist = &mut self.current_calculation.projectile_data.twist;
+                        ui.horizontal(|ui| {
+                            let mut has_twist = twist.is_some();
+                            if ui.checkbox(&mut has_twist, "Twist 1:").changed() {
+                                *twist = has_twist.then(TwistRate::default);
+                            }
+                            if let Some(twist) = twist {
+                                ui.add(
+                                    egui::DragValue::new(&mut twist.inches)
+                                        .speed(0.1)
+                                        .range(1.0..=60.0)
+                                        .suffix(" in"),
+                                );
+                                ui.selectable_value(&mut twist.direction, TwistDirection::Right, "RH");
+                                ui.selectable_value(&mut twist.direction, TwistDirection::Left, "LH");
+                            }
+                        });
                     });
 
                     columns[1].group(|ui| {
@@ -581,6 +1186,106 @@ impl BallisticsApp {
                                     .range(0.1..=2.0),
                             );
                         });
+                        ui.horizontal(|ui| {
+                            ui.label("Drag Model:");
+                            let drag_model = &mut self.current_calculation.projectile_data.drag_model;
+                            egui::ComboBox::from_id_source("drag_model")
+                                .selected_text(drag_model.as_str())
+                                .show_ui(ui, |ui| {
+                                    for model in DragModel::ALL {
+                                        ui.selectable_value(drag_model, model, model.as_str())
+                                            .on_hover_text(model.description());
+                                    }
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Drag Curve:");
+                            let selected_text = self.current_calculation.projectile_data.drag_curve
+                                .as_ref()
+                                .map(|c| c.name.clone())
+                                .unwrap_or_else(|| "None (use BC)".to_string());
+                            let curves = &self.drag_curves;
+                            let drag_curve = &mut self.current_calculation.projectile_data.drag_curve;
+                            egui::ComboBox::from_id_source("drag_curve")
+                                .selected_text(selected_text)
+                                .show_ui(ui, |ui| {
+                                    if ui.selectable_label(drag_curve.is_none(), "None (use BC)").clicked() {
+                                        *drag_curve = None;
+                                    }
+                                    for curve in curves {
+                                        let selected = drag_curve.as_ref().map(|c| &c.id) == Some(&curve.id);
+                                        if ui.selectable_label(selected, &curve.name).clicked() {
+                                            *drag_curve = Some(curve.clone());
+                                        }
+                                    }
+                                });
+                            if ui.button("📥 Import").clicked() {
+                                self.import_drag_curve();
+                            }
+                            let selected_id = self.current_calculation.projectile_data.drag_curve
+                                .as_ref()
+                                .map(|c| c.id.clone());
+                            if let Some(id) = selected_id {
+                                if ui.button("🗑").on_hover_text("Delete this curve from the library").clicked() {
+                                    self.storage.delete_drag_curve(&id);
+                                    self.drag_curves.retain(|c| c.id != id);
+                                    self.current_calculation.projectile_data.drag_curve = None;
+                                }
+                            }
+                        });
+                        if let Some(curve) = &mut self.current_calculation.projectile_data.drag_curve {
+                            let (min_mach, max_mach) = curve.mach_range();
+                            ui.horizontal(|ui| {
+                                ui.label("Curve Diameter:");
+                                let response = ui.add(
+                                    egui::DragValue::new(&mut curve.diameter)
+                                        .speed(0.001)
+                                        .range(0.0..=1.0)
+                                        .suffix(" in"),
+                                );
+                                // Keep the library copy in step so re-selecting the curve keeps the edit
+                                if let Some(stored) = self.drag_curves.iter_mut().find(|c| c.id == curve.id) {
+                                    if response.changed() {
+                                        stored.diameter = curve.diameter;
+                                    }
+                                    if response.drag_stopped() || response.lost_focus() {
+                                        self.storage.save_drag_curves(std::slice::from_ref(stored));
+                                    }
+                                }
+                                ui.label(format!("Mach {:.2}-{:.2}", min_mach, max_mach));
+                            });
+                        }
+                        ui.collapsing("Velocity-Banded BC", |ui| {
+                            let segments = &mut self.current_calculation.projectile_data.bc_segments;
+                            let mut to_remove = None;
+                            for (i, segment) in segments.iter_mut().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.label("Above");
+                                    ui.add(
+                                        egui::DragValue::new(&mut segment.velocity)
+                                            .speed(10.0)
+                                            .range(0.0..=5000.0)
+                                            .suffix(" fps"),
+                                    );
+                                    ui.label("BC");
+                                    ui.add(
+                                        egui::DragValue::new(&mut segment.bc)
+                                            .speed(0.001)
+                                            .range(0.1..=2.0),
+                                    );
+                                    if ui.small_button("❌").clicked() {
+                                        to_remove = Some(i);
+                                    }
+                                });
+                            }
+                            if let Some(i) = to_remove {
+                                segments.remove(i);
+                            }
+                            if ui.button("➕ Add Band").clicked() {
+                                let bc = self.current_calculation.projectile_data.bc;
+                                segments.push(BcSegment { velocity: 0.0, bc });
+                            }
+                        });
                         ui.horizontal(|ui| {
                             ui.label("Zero Range:");
                             ui.add(
@@ -597,6 +1302,26 @@ impl BallisticsApp {
                                     .suffix(" inches"),
                             );
                         });
+                        ui.horizontal(|ui| {
+                            ui.label("Shot Angle:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.shot_angle)
+                                    .speed(0.5)
+                                    .range(-90.0..=90.0)
+                                    .suffix(" °"),
+                            )
+                            .on_hover_text("Line-of-sight inclination, positive = uphill");
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Cant:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.cant)
+                                    .speed(0.5)
+                                    .range(-45.0..=45.0)
+                                    .suffix(" °"),
+                            )
+                            .on_hover_text("Rifle rotation about the line of sight, positive = clockwise");
+                        });
                     });
                 });
             });
@@ -606,74 +1331,374 @@ impl BallisticsApp {
         egui::CollapsingHeader::new("🌤️ Environmental Conditions")
             .default_open(true)
             .show(ui, |ui| {
-                if let Some(weather) = &self.current_calculation.weather_data {
-                    ui.colored_label(
-                        egui::Color32::GREEN,
-                        format!(
-                            "📡 Live Data: {}°F, {:.2}\" Hg, {}% RH",
-                            weather.temperature, weather.pressure, weather.humidity
-                        ),
-                    );
+                if let Some(weather) = &self.current_calculation.weather_data {
+                    ui.colored_label(
+                        egui::Color32::GREEN,
+                        format!(
+                            "📡 Live Data: {}°F, {:.2}\" Hg, {}% RH",
+                            weather.temperature, weather.pressure, weather.humidity
+                        ),
+                    );
+                    ui.separator();
+                }
+
+                ui.columns(2, |columns| {
+                    columns[0].group(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Temperature:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.temperature)
+                                    .speed(1.0)
+                                    .suffix(" °F"),
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Pressure:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.pressure)
+                                    .speed(0.01)
+                                    .suffix(" inHg"),
+                            );
+                            let kind = &mut self.current_calculation.projectile_data.pressure_kind;
+                            egui::ComboBox::from_id_source("pressure_kind")
+                                .selected_text(match kind {
+                                    PressureKind::Station => "Station",
+                                    PressureKind::Barometric => "Barometric",
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(kind, PressureKind::Station, "Station");
+                                    ui.selectable_value(kind, PressureKind::Barometric, "Barometric");
+                                });
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Humidity:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.humidity)
+                                    .speed(1.0)
+                                    .suffix(" %")
+                                    .range(0.0..=100.0),
+                            );
+                        });
+                    });
+
+                    columns[1].group(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Wind Speed:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.wind_speed)
+                                    .speed(0.1)
+                                    .suffix(" mph"),
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Wind Angle:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.wind_angle)
+                                    .speed(1.0)
+                                    .suffix(" °")
+                                    .range(0.0..=360.0),
+                            );
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("Altitude:");
+                            ui.add(
+                                egui::DragValue::new(&mut self.current_calculation.projectile_data.altitude)
+                                    .speed(10.0)
+                                    .suffix(" ft"),
+                            );
+                        });
+                        let current_density_altitude =
+                            self.current_calculation.projectile_data.atmosphere().density_altitude.round();
+                        let density_altitude = &mut self.current_calculation.projectile_data.density_altitude;
+                        ui.horizontal(|ui| {
+                            let mut use_density_altitude = density_altitude.is_some();
+                            if ui.checkbox(&mut use_density_altitude, "Density Altitude:").changed() {
+                                *density_altitude = use_density_altitude.then_some(current_density_altitude);
+                            }
+                            if let Some(value) = density_altitude {
+                                ui.add(egui::DragValue::new(value).speed(10.0).suffix(" ft"));
+                            }
+                        });
+                    });
+                });
+
+                ui.separator();
+
+                ui.collapsing("Wind Zones", |ui| {
+                    ui.label("Zones override the wind above over their span.");
+                    let zones = &mut self.current_calculation.projectile_data.wind_zones;
+                    let mut to_remove = None;
+                    for (i, zone) in zones.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Z{}", i + 1));
+                            ui.add(egui::DragValue::new(&mut zone.start).speed(5.0).range(0.0..=10000.0));
+                            ui.label("to");
+                            ui.add(
+                                egui::DragValue::new(&mut zone.end)
+                                    .speed(5.0)
+                                    .range(0.0..=10000.0)
+                                    .suffix(" yds"),
+                            );
+                            ui.add(egui::DragValue::new(&mut zone.speed).speed(0.1).suffix(" mph"));
+                            ui.label("from");
+                            ui.add(
+                                egui::DragValue::new(&mut zone.angle)
+                                    .speed(1.0)
+                                    .range(0.0..=360.0)
+                                    .suffix(" °"),
+                            );
+                            ui.label("Vertical:");
+                            ui.add(egui::DragValue::new(&mut zone.vertical).speed(0.1).suffix(" mph"));
+                            if ui.small_button("❌").clicked() {
+                                to_remove = Some(i);
+                            }
+                        });
+                    }
+                    if let Some(i) = to_remove {
+                        zones.remove(i);
+                    }
+                    if ui.button("➕ Add Zone").clicked() {
+                        let start = zones.last().map_or(0.0, |z| z.end);
+                        zones.push(WindZone {
+                            start,
+                            end: start + 300.0,
+                            speed: self.current_calculation.projectile_data.wind_speed,
+                            angle: self.current_calculation.projectile_data.wind_angle,
+                            vertical: 0.0,
+                        });
+                    }
+                });
+
+                ui.separator();
+
+                let target_height = &mut self.current_calculation.projectile_data.target_height;
+                ui.horizontal(|ui| {
+                    let mut has_height = target_height.is_some();
+                    if ui
+                        .checkbox(&mut has_height, "Danger space for target height:")
+                        .on_hover_text("Range error each hold can absorb and still hit the target")
+                        .changed()
+                    {
+                        *target_height = has_height.then_some(20.0);
+                    }
+                    if let Some(height) = target_height {
+                        ui.add(
+                            egui::DragValue::new(height)
+                                .speed(0.5)
+                                .range(1.0..=120.0)
+                                .suffix(" in"),
+                        );
+                    }
+                });
+
+                let moving_target = &mut self.current_calculation.projectile_data.moving_target;
+                let mut is_moving = moving_target.is_some();
+                if ui.checkbox(&mut is_moving, "Moving target").changed() {
+                    *moving_target = is_moving.then(MovingTarget::default);
+                }
+                if let Some(target) = moving_target {
+                    ui.horizontal(|ui| {
+                        ui.label("Target Speed:");
+                        ui.add(
+                            egui::DragValue::new(&mut target.speed)
+                                .speed(0.1)
+                                .range(0.0..=60.0)
+                                .suffix(" mph"),
+                        );
+                        for (label, speed) in [("Walk", 3.0), ("Trot", 8.0), ("Run", 20.0)] {
+                            if ui.small_button(label).clicked() {
+                                target.speed = speed;
+                            }
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Heading:");
+                        ui.add(
+                            egui::DragValue::new(&mut target.angle)
+                                .speed(1.0)
+                                .range(0.0..=360.0)
+                                .suffix(" °"),
+                        )
+                        .on_hover_text("Direction of travel: 0 = away, 90 = to the right, 270 = to the left");
+                        ui.label("Body Length:");
+                        ui.add(
+                            egui::DragValue::new(&mut target.length)
+                                .speed(0.5)
+                                .range(0.0..=200.0)
+                                .suffix(" in"),
+                        );
+                    });
+                }
+
+                let game_class = &mut self.current_calculation.projectile_data.game_class;
+                let mut has_game = game_class.is_some();
+                if ui
+                    .checkbox(&mut has_game, "Game minimums")
+                    .on_hover_text("Energy and velocity the bullet must keep to take the game cleanly")
+                    .changed()
+                {
+                    *game_class = has_game.then(GameClass::default);
+                }
+                if let Some(game) = game_class {
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_source("game_class")
+                            .selected_text(game.name.clone())
+                            .show_ui(ui, |ui| {
+                                for preset in GameClass::presets() {
+                                    let selected = game.name == preset.name;
+                                    if ui.selectable_label(selected, preset.name.as_str()).clicked() {
+                                        *game = preset;
+                                    }
+                                }
+                            });
+                        ui.label("Min Energy:");
+                        ui.add(
+                            egui::DragValue::new(&mut game.min_energy)
+                                .speed(10.0)
+                                .range(0.0..=10000.0)
+                                .suffix(" ft-lb"),
+                        );
+                        ui.label("Min Velocity:");
+                        ui.add(
+                            egui::DragValue::new(&mut game.min_velocity)
+                                .speed(10.0)
+                                .range(0.0..=5000.0)
+                                .suffix(" fps"),
+                        )
+                        .on_hover_text("e.g. the bullet's minimum expansion velocity, 0 for none");
+                    });
+                }
+
+                ui.separator();
+
+                if self.settings.include_coriolis {
+                    ui.horizontal(|ui| {
+                        ui.label("Latitude:");
+                        ui.add(
+                            egui::DragValue::new(&mut self.current_calculation.projectile_data.latitude)
+                                .speed(0.1)
+                                .range(-90.0..=90.0)
+                                .suffix(" °"),
+                        );
+                        ui.label("Azimuth of Fire:");
+                        ui.add(
+                            egui::DragValue::new(&mut self.current_calculation.projectile_data.azimuth)
+                                .speed(1.0)
+                                .range(0.0..=360.0)
+                                .suffix(" °"),
+                        );
+                    });
+                    ui.separator();
+                }
+
+                let zero_conditions = &mut self.current_calculation.projectile_data.zero_conditions;
+                let mut separate_zero = zero_conditions.is_some();
+                if ui.checkbox(&mut separate_zero, "Zeroed in different conditions").changed() {
+                    *zero_conditions = separate_zero.then(ZeroConditions::default);
+                }
+
+                if let Some(zero) = zero_conditions {
+                    ui.horizontal(|ui| {
+                        ui.label("Zero Temp:");
+                        ui.add(egui::DragValue::new(&mut zero.temperature).speed(1.0).suffix(" °F"));
+                        ui.label("Pressure:");
+                        ui.add(egui::DragValue::new(&mut zero.pressure).speed(0.01).suffix(" inHg"));
+                        ui.label("Humidity:");
+                        ui.add(
+                            egui::DragValue::new(&mut zero.humidity)
+                                .speed(1.0)
+                                .suffix(" %")
+                                .range(0.0..=100.0),
+                        );
+                        ui.label("Altitude:");
+                        ui.add(egui::DragValue::new(&mut zero.altitude).speed(10.0).suffix(" ft"));
+                    });
+                }
+            });
+    }
+
+    fn show_range_table_section(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("📏 Range Table")
+            .default_open(false)
+            .show(ui, |ui| {
+                let table = &mut self.current_calculation.projectile_data.range_table;
+
+                ui.horizontal(|ui| {
+                    ui.label("Units:");
+                    ui.selectable_value(&mut table.unit, RangeUnit::Yards, "Yards");
+                    ui.selectable_value(&mut table.unit, RangeUnit::Meters, "Meters");
+
                     ui.separator();
-                }
 
-                ui.columns(2, |columns| {
-                    columns[0].group(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.label("Temperature:");
-                            ui.add(
-                                egui::DragValue::new(&mut self.current_calculation.projectile_data.temperature)
-                                    .speed(1.0)
-                                    .suffix(" °F"),
-                            );
-                        });
-                        ui.horizontal(|ui| {
-                            ui.label("Pressure:");
-                            ui.add(
-                                egui::DragValue::new(&mut self.current_calculation.projectile_data.pressure)
-                                    .speed(0.01)
-                                    .suffix(" inHg"),
-                            );
-                        });
+                    let is_interval = matches!(table.spec, RangeSpec::Interval { .. });
+                    if ui.selectable_label(is_interval, "Interval").clicked() && !is_interval {
+                        let end = table
+                            .distances()
+                            .ok()
+                            .and_then(|d| d.last().copied())
+                            .unwrap_or(1000.0);
+                        table.spec = RangeSpec::Interval { start: 0.0, end, step: 100.0 };
+                    }
+                    if ui.selectable_label(!is_interval, "List").clicked() && is_interval {
+                        table.spec = RangeSpec::List(table.distances().unwrap_or_default());
+                    }
+                });
+
+                let unit = table.unit.abbreviation();
+                match &mut table.spec {
+                    RangeSpec::Interval { start, end, step } => {
                         ui.horizontal(|ui| {
-                            ui.label("Humidity:");
+                            ui.label("From:");
                             ui.add(
-                                egui::DragValue::new(&mut self.current_calculation.projectile_data.humidity)
-                                    .speed(1.0)
-                                    .suffix(" %")
-                                    .range(0.0..=100.0),
+                                egui::DragValue::new(start)
+                                    .speed(5.0)
+                                    .range(0.0..=10000.0)
+                                    .suffix(format!(" {}", unit)),
                             );
-                        });
-                    });
-
-                    columns[1].group(|ui| {
-                        ui.horizontal(|ui| {
-                            ui.label("Wind Speed:");
+                            ui.label("To:");
                             ui.add(
-                                egui::DragValue::new(&mut self.current_calculation.projectile_data.wind_speed)
-                                    .speed(0.1)
-                                    .suffix(" mph"),
+                                egui::DragValue::new(end)
+                                    .speed(5.0)
+                                    .range(0.0..=10000.0)
+                                    .suffix(format!(" {}", unit)),
                             );
-                        });
-                        ui.horizontal(|ui| {
-                            ui.label("Wind Angle:");
+                            ui.label("Step:");
                             ui.add(
-                                egui::DragValue::new(&mut self.current_calculation.projectile_data.wind_angle)
+                                egui::DragValue::new(step)
                                     .speed(1.0)
-                                    .suffix(" °")
-                                    .range(0.0..=360.0),
+                                    .range(1.0..=1000.0)
+                                    .suffix(format!(" {}", unit)),
                             );
                         });
-                        ui.horizontal(|ui| {
-                            ui.label("Altitude:");
-                            ui.add(
-                                egui::DragValue::new(&mut self.current_calculation.projectile_data.altitude)
-                                    .speed(10.0)
-                                    .suffix(" ft"),
-                            );
+                    }
+                    RangeSpec::List(distances) => {
+                        let mut to_remove = None;
+                        ui.horizontal_wrapped(|ui| {
+                            for (i, distance) in distances.iter_mut().enumerate() {
+                                ui.add(
+                                    egui::DragValue::new(distance)
+                                        .speed(5.0)
+                                        .range(0.0..=10000.0)
+                                        .suffix(format!(" {}", unit)),
+                                );
+                                if ui.small_button("❌").clicked() {
+                                    to_remove = Some(i);
+                                }
+                            }
                         });
-                    });
-                });
+                        if let Some(i) = to_remove {
+                            distances.remove(i);
+                        }
+                        if ui.button("➕ Add Distance").clicked() {
+                            let next = distances.iter().copied().fold(0.0, f64::max) + 100.0;
+                            distances.push(next);
+                        }
+                    }
+                }
+
+                if let Err(e) = table.distances() {
+                    ui.colored_label(egui::Color32::from_rgb(255, 200, 100), format!("⚠️ {}", e));
+                }
             });
     }
 
@@ -818,6 +1843,9 @@ impl BallisticsApp {
                                 ui.horizontal(|ui| {
                                     ui.label("Twist Rate:");
                                     ui.text_edit_singleline(&mut profile.twist_rate);
+                                    if let Err(e) = profile.twist() {
+                                        ui.colored_label(egui::Color32::from_rgb(255, 200, 100), format!("⚠️ {}", e));
+                                    }
                                 });
                                 ui.horizontal(|ui| {
                                     ui.label("Sight Height:");
@@ -830,6 +1858,49 @@ impl BallisticsApp {
                             });
                         });
 
+                        let mut has_scope = profile.scope.is_some();
+                        if ui.checkbox(&mut has_scope, "Scope turrets").changed() {
+                            profile.scope = has_scope.then(ScopeTurret::default);
+                        }
+                        if let Some(scope) = &mut profile.scope {
+                            ui.horizontal(|ui| {
+                                ui.label("Units:");
+                                for unit in TurretUnit::ALL {
+                                    ui.selectable_value(&mut scope.unit, unit, unit.abbreviation());
+                                }
+                                ui.label("Click:");
+                                ui.add(
+                                    egui::DragValue::new(&mut scope.click_value)
+                                        .speed(0.01)
+                                        .range(0.01..=1.0)
+                                        .suffix(format!(" {}", scope.unit)),
+                                );
+                                ui.label("Elevation Travel:");
+                                ui.add(
+                                    egui::DragValue::new(&mut scope.max_elevation)
+                                        .speed(0.5)
+                                        .range(0.0..=200.0)
+                                        .suffix(format!(" {}", scope.unit)),
+                                );
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label("Tracking Factor:");
+                                ui.add(
+                                    egui::DragValue::new(&mut scope.tracking_factor)
+                                        .speed(0.001)
+                                        .range(0.8..=1.2),
+                                )
+                                .on_hover_text("Measured movement ÷ dialed, from a tall-target test");
+                                ui.label("Base Cant:");
+                                ui.add(
+                                    egui::DragValue::new(&mut scope.base_cant)
+                                        .speed(1.0)
+                                        .range(0.0..=60.0)
+                                        .suffix(" MOA"),
+                                );
+                            });
+                        }
+
                         ui.separator();
                         ui.label("Notes:");
                         ui.text_edit_multiline(&mut profile.notes);
@@ -1011,11 +2082,26 @@ impl BallisticsApp {
                             columns[0].vertical(|ui| {
                                 ui.label(format!("Caliber: {}", load.caliber));
                                 ui.label(format!("Bullet Weight: {} gr", load.bullet_weight));
+                                if let Some(diameter) = load.bullet_diameter {
+                                    ui.label(format!("Bullet Diameter: {:.3}\"", diameter));
+                                }
                                 ui.label(format!("Muzzle Velocity: {} fps", load.velocity));
+                                if let Some(length) = load.test_barrel_length {
+                                    ui.label(format!("Test Barrel: {}\"", length));
+                                }
+                                if let Some(powder) = &load.powder_sensitivity {
+                                    ui.label(format!(
+                                        "Temp Sensitivity: {} fps/°F from {}°F",
+                                        powder.fps_per_degree, powder.reference_temperature
+                                    ));
+                                }
                             });
 
                             columns[1].vertical(|ui| {
-                                ui.label(format!("BC: {:.3}", load.bc));
+                                ui.label(format!("BC: {:.3} ({})", load.bc, load.drag_model));
+                                for segment in &load.bc_segments {
+                                    ui.label(format!("  {:.3} above {:.0} fps", segment.bc, segment.velocity));
+                                }
                                 ui.label(format!("Powder: {}", load.powder_type));
                                 ui.label(format!("Charge: {} gr", load.powder_charge));
                             });
@@ -1417,16 +2503,73 @@ impl BallisticsApp {
 
         ui.horizontal(|ui| {
             ui.group(|ui| {
-                ui.label(format!("Max Range: {} yards", results.max_range));
+                ui.label(format!("Max Range: {} {}", results.max_range, results.range_unit));
             });
             ui.group(|ui| {
-                ui.label(format!("Max Ordinate: {:.1} inches", results.max_ordinate));
+                ui.label(format!(
+                    "Max Ordinate: {:.1} inches at {:.0} {}",
+                    results.max_ordinate,
+                    results.max_ordinate_range,
+                    results.range_unit.abbreviation()
+                ));
             });
             ui.group(|ui| {
                 ui.label(format!("Zero Offset: {:.2} MOA", results.zero_offset));
             });
+            ui.group(|ui| {
+                ui.label(format!("Density Altitude: {:.0} ft", results.density_altitude));
+            });
+            if results.shot_angle != 0.0 {
+                ui.group(|ui| {
+                    ui.label(format!("Shot Angle: {:+.1}°", results.shot_angle));
+                });
+            }
+        });
+
+        let transition_label = |range: Option<f64>| match range {
+            Some(range) => format!("{:.0} {}", range, results.range_unit),
+            None => "not reached".to_string(),
+        };
+        ui.horizontal(|ui| {
+            ui.group(|ui| {
+                ui.label(format!("Muzzle Velocity: {:.0} fps", results.muzzle_velocity));
+            });
+            ui.group(|ui| {
+                ui.label(format!("Speed of Sound: {:.0} fps", results.speed_of_sound));
+            });
+            ui.group(|ui| {
+                ui.label(format!("Transonic (M1.2): {}", transition_label(results.transonic_range)));
+            });
+            ui.group(|ui| {
+                ui.label(format!("Subsonic (M1.0): {}", transition_label(results.subsonic_range)));
+            });
+            if let Some(stability) = results.stability {
+                ui.group(|ui| {
+                    ui.label(format!("Stability (Sg): {:.2}", stability));
+                });
+            }
+            if let Some(game) = &results.game_class {
+                ui.group(|ui| {
+                    ui.label(match results.ethical_range {
+                        Some(ethical) => format!(
+                            "Max Ethical Range ({}): {:.0} {}, {} limited",
+                            game.name, ethical.range, results.range_unit, ethical.limit
+                        ),
+                        // Still above the minimums wherever the zeroed bore
+                        // angle can carry it, to the end of its flight
+                        None => format!(
+                            "Max Ethical Range ({}): the maximum range at the zero elevation",
+                            game.name
+                        ),
+                    });
+                });
+            }
         });
 
+        for warning in &results.warnings {
+            ui.colored_label(egui::Color32::from_rgb(255, 200, 100), format!("⚠️ {}", warning));
+        }
+
         ui.separator();
 
         // Trajectory graph would go here
@@ -1443,6 +2586,32 @@ impl BallisticsApp {
     fn show_trajectory_table(&self, ui: &mut egui::Ui, results: &TrajectoryResult) {
         ui.label("Detailed Trajectory Data:");
 
+        let inclined = results.shot_angle != 0.0;
+        let zone_count = results
+            .trajectory_points
+            .first()
+            .map_or(0, |p| p.zone_drift.len());
+        let show_earth_rotation = results
+            .trajectory_points
+            .iter()
+            .any(|p| p.coriolis_drift != 0.0 || p.eotvos_drop != 0.0);
+        let show_jump = results
+            .trajectory_points
+            .iter()
+            .any(|p| p.aerodynamic_jump != 0.0);
+        let show_lead = results.trajectory_points.iter().any(|p| p.lead.is_some());
+        let show_danger_space = results
+            .trajectory_points
+            .iter()
+            .any(|p| p.danger_space.is_some());
+        let turret_unit = results.turret.as_ref().map(|t| t.unit);
+        let show_cant = results
+            .trajectory_points
+            .iter()
+            .any(|p| p.cant_drift != 0.0 || p.cant_drop != 0.0);
+        let show_ko = results.trajectory_points.iter().any(|p| p.taylor_ko > 0.0);
+        let below_minimum = egui::Color32::from_rgb(255, 120, 120);
+
         egui::ScrollArea::vertical()
     .id_source("trajectory_table_scroll")
     .max_height(400.0)
@@ -1453,36 +2622,221 @@ impl BallisticsApp {
                     .show(ui, |ui| {
                         // Headers
                         ui.strong("Range");
+                        if inclined {
+                            ui.strong("Horizontal");
+                        }
                         ui.strong("Drop");
                         ui.strong("Drift");
                         ui.strong("Velocity");
+                        ui.strong("Mach");
                         ui.strong("Energy");
+                        ui.strong("Momentum");
+                        if show_ko {
+                            ui.strong("Taylor KO");
+                        }
                         ui.strong("Time");
                         ui.strong("MOA");
                         ui.strong("MIL");
+                        if turret_unit.is_some() {
+                            ui.strong("Elev Clicks");
+                            ui.strong("Wind Clicks");
+                        }
+                        if show_earth_rotation {
+                            ui.strong("Coriolis");
+                            ui.strong("Eötvös");
+                        }
+                        if show_jump {
+                            ui.strong("Aero Jump");
+                        }
+                        if show_cant {
+                            ui.strong("Cant Vert");
+                            ui.strong("Cant Horiz");
+                        }
+                        if show_lead {
+                            ui.strong("Lead");
+                            ui.strong("Lead");
+                            ui.strong("Lead");
+                            ui.strong("Lead");
+                        }
+                        if show_danger_space {
+                            ui.strong("Danger Space");
+                            ui.strong("Depth");
+                        }
+                        for zone in 0..zone_count {
+                            ui.strong(format!("Wind Z{}", zone + 1));
+                        }
                         ui.end_row();
 
                         // Units
-                        ui.label("(yards)");
+                        ui.label(format!("({})", results.range_unit));
+                        if inclined {
+                            ui.label(format!("({})", results.range_unit));
+                        }
                         ui.label("(inches)");
                         ui.label("(inches)");
                         ui.label("(fps)");
+                        ui.label("");
                         ui.label("(ft-lb)");
+                        ui.label("(lb·s)");
+                        if show_ko {
+                            ui.label("");
+                        }
                         ui.label("(sec)");
                         ui.label("(adj)");
                         ui.label("(adj)");
+                        if let Some(unit) = turret_unit {
+                            ui.label(format!("({} up)", unit));
+                            ui.label(format!("({} right)", unit));
+                        }
+                        if show_earth_rotation {
+                            ui.label("(inches)");
+                            ui.label("(inches)");
+                        }
+                        if show_jump {
+                            ui.label("(inches)");
+                        }
+                        if show_cant {
+                            ui.label("(inches)");
+                            ui.label("(inches)");
+                        }
+                        if show_lead {
+                            ui.label("(inches)");
+                            ui.label("(MOA)");
+                            ui.label("(MIL)");
+                            ui.label("(lengths)");
+                        }
+                        if show_danger_space {
+                            ui.label(format!("({})", results.range_unit));
+                            ui.label(format!("({})", results.range_unit));
+                        }
+                        for _ in 0..zone_count {
+                            ui.label("(inches)");
+                        }
                         ui.end_row();
 
                         // Data rows
                         for point in &results.trajectory_points {
                             ui.label(format!("{:.0}", point.distance));
+                            if inclined {
+                                ui.label(format!("{:.0}", point.horizontal_distance));
+                            }
                             ui.label(format!("{:.1}", point.drop));
                             ui.label(format!("{:.1}", point.drift));
-                            ui.label(format!("{:.0}", point.velocity));
-                            ui.label(format!("{:.0}", point.energy));
+                            let game = results.game_class.as_ref();
+                            let velocity = format!("{:.0}", point.velocity);
+                            if game.is_some_and(|g| point.velocity < g.min_velocity) {
+                                ui.colored_label(below_minimum, velocity);
+                            } else {
+                                ui.label(velocity);
+                            }
+                            ui.label(format!("{:.2}", point.mach));
+                            let energy = format!("{:.0}", point.energy);
+                            if game.is_some_and(|g| point.energy < g.min_energy) {
+                                ui.colored_label(below_minimum, energy);
+                            } else {
+                                ui.label(energy);
+                            }
+                            ui.label(format!("{:.2}", point.momentum));
+                            if show_ko {
+                                ui.label(format!("{:.1}", point.taylor_ko));
+                            }
                             ui.label(format!("{:.3}", point.time));
                             ui.label(format!("{:.1}", point.moa_adjustment));
                             ui.label(format!("{:.2}", point.mil_adjustment));
+                            if turret_unit.is_some() {
+                                let clicks = point.clicks.unwrap_or_default();
+                                ui.label(format!("{:.1} ({})", clicks.elevation, clicks.elevation_rounded));
+                                ui.label(format!("{:.1} ({})", clicks.windage, clicks.windage_rounded));
+                            }
+                            if show_earth_rotation {
+                                ui.label(format!("{:.2}", point.coriolis_drift));
+                                ui.label(format!("{:.2}", point.eotvos_drop));
+                            }
+                            if show_jump {
+                                ui.label(format!("{:.2}", point.aerodynamic_jump));
+                            }
+                            if show_cant {
+                                ui.label(format!("{:.2}", point.cant_drop));
+                                ui.label(format!("{:.2}", point.cant_drift));
+                            }
+                            if show_lead {
+                                let lead = point.lead.clone().unwrap_or_default();
+                                ui.label(format!("{:.1}", lead.inches));
+                                ui.label(format!("{:.1}", lead.moa));
+                                ui.label(format!("{:.2}", lead.mil));
+                                ui.label(format!("{:.2}", lead.lengths));
+                            }
+                            if show_danger_space {
+                                match point.danger_space {
+                                    Some(space) => {
+                                        ui.label(format!("{:.0}–{:.0}", space.near, space.far));
+                                        ui.label(format!("{:.0}", space.depth));
+                                    }
+                                    None => {
+                                        ui.label("");
+                                        ui.label("");
+                                    }
+                                }
+                            }
+                            for drift in &point.zone_drift {
+                                ui.label(format!("{:.1}", drift));
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    
+
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("Wind Bracket (full-value crosswind, hold into the wind):");
+            if results.wind_constant > 0.0 {
+                ui.label(format!("Wind constant: {:.1}", results.wind_constant))
+                    .on_hover_text("MOA hold ≈ range in hundreds of yards × wind mph ÷ constant");
+            }
+            if ui.button("📋 Copy Wind Card").clicked() {
+                self.copy_wind_card_to_clipboard(results);
+            }
+            if ui.button("📋 Copy Range Card").clicked() {
+                self.copy_range_card_to_clipboard(results);
+            }
+        });
+
+        egui::ScrollArea::vertical()
+    .id_source("wind_bracket_scroll")
+    .max_height(300.0)
+    .show(ui, |ui| {
+                egui::Grid::new("wind_bracket_grid")
+                    .striped(true)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.strong("Range");
+                        for speed in WIND_BRACKET_SPEEDS {
+                            ui.strong(format!("{} mph", speed));
+                            ui.strong("");
+                            ui.strong("");
+                        }
+                        ui.strong("Constant");
+                        ui.end_row();
+
+                        ui.label(format!("({})", results.range_unit));
+                        for _ in WIND_BRACKET_SPEEDS {
+                            ui.label("(inches)");
+                            ui.label("(MOA)");
+                            ui.label("(MIL)");
+                        }
+                        ui.label("");
+                        ui.end_row();
+
+                        for point in &results.trajectory_points {
+                            ui.label(format!("{:.0}", point.distance));
+                            for hold in &point.wind_holds {
+                                ui.label(format!("{:.1}", hold.drift));
+                                ui.label(format!("{:.1}", hold.moa));
+                                ui.label(format!("{:.2}", hold.mil));
+                            }
+                            ui.label(format!("{:.1}", point.wind_constant));
                             ui.end_row();
                         }
                     });
@@ -1491,23 +2845,43 @@ impl BallisticsApp {
 
     // Helper methods
     fn new_calculation(&mut self) {
+        let mut projectile_data = ProjectileData::default();
+        if self.settings.distance_unit == DistanceUnit::Meters {
+            projectile_data.range_table = RangeTable::interval(RangeUnit::Meters, 0.0, 1000.0, 50.0);
+        }
         self.current_calculation = CalculationData {
             id: Uuid::new_v4().to_string(),
-            projectile_data: ProjectileData::default(),
+            projectile_data,
             notes: String::new(),
             weather_data: None,
             range_data: None,
             timestamp: Utc::now().to_rfc3339(),
         };
         self.trajectory_results = None;
+        self.dispersion_results = None;
+        self.dispersion_job = None;
+        self.applied_load = None;
+        self.barrel_adjustment = None;
+        self.point_blank = None;
+        self.maximum_range = None;
         self.attached_images.clear();
         self.current_screen = Screen::Analysis;
     }
 
+    // Solver for every calculation, with the current settings applied
+    fn calculator(&self) -> BallisticsCalculator {
+        BallisticsCalculator {
+            include_coriolis: self.settings.include_coriolis,
+            ..self.calculator.clone()
+        }
+    }
+
     fn calculate_trajectory(&mut self) {
         self.current_calculation.timestamp = Utc::now().to_rfc3339();
+        self.dispersion_results = None;
+        self.dispersion_job = None;
         self.trajectory_results =
-            Some(self.calculator.calculate(&self.current_calculation.projectile_data));
+            Some(self.calculator().calculate(&self.current_calculation.projectile_data));
     }
 
     fn save_calculation(&mut self) {
@@ -1561,7 +2935,19 @@ impl BallisticsApp {
             if !profile.caliber.is_empty() {
                 self.current_calculation.projectile_data.caliber = profile.caliber.clone();
             }
+
+            match profile.twist() {
+                Ok(twist) => self.current_calculation.projectile_data.twist = Some(twist),
+                Err(e) => self.error_message = Some(e),
+            }
+            self.current_calculation.projectile_data.turret = profile.scope.clone();
         }
+        self.apply_barrel_adjustment();
+    }
+
+    fn apply_range_data(&mut self, data: RangefinderData) {
+        self.current_calculation.projectile_data.shot_angle = data.angle;
+        self.current_calculation.range_data = Some(data);
     }
 
     fn apply_weather_data(&mut self, data: WeatherData) {
@@ -1578,6 +2964,61 @@ impl BallisticsApp {
         self.current_calculation.projectile_data.mass = load.bullet_weight;
         self.current_calculation.projectile_data.velocity = load.velocity;
         self.current_calculation.projectile_data.bc = load.bc;
+        self.current_calculation.projectile_data.drag_model = load.drag_model;
+        self.current_calculation.projectile_data.bc_segments = load.bc_segments.clone();
+        self.current_calculation.projectile_data.powder = load.powder_sensitivity.clone();
+        if let Some(diameter) = load.bullet_diameter {
+            self.current_calculation.projectile_data.bullet_diameter = diameter;
+        }
+        self.applied_load = Some(load.clone());
+        self.apply_barrel_adjustment();
+    }
+
+    // Trued values for the applied load and drag model, from the selected
+    // profile or else the load itself. Records fitted against another load or
+    // drag model are ignored.
+    fn active_truing(&self) -> Option<&TruingRecord> {
+        let load = self.applied_load.as_ref().map(|load| load.key());
+        let drag_model = self.current_calculation.projectile_data.drag_model;
+        let profile = self.selected_profile.and_then(|i| self.firearm_profiles.get(i));
+        profile
+            .and_then(|profile| profile.truing.as_ref())
+            .into_iter()
+            .chain(self.applied_load.as_ref().and_then(|load| load.truing.as_ref()))
+            .find(|truing| truing.matches(load.as_deref(), drag_model))
+    }
+
+    // Set velocity and BC from the applied load, then either the matching
+    // trued values, which were measured in this rifle, or an estimate for the
+    // selected profile's barrel from the load's test barrel.
+    fn apply_barrel_adjustment(&mut self) {
+        self.barrel_adjustment = None;
+        let truing = self.active_truing().cloned();
+        let data = &mut self.current_calculation.projectile_data;
+        if let Some(load) = &self.applied_load {
+            data.velocity = load.velocity;
+            data.bc = load.bc;
+            data.bc_segments = load.bc_segments.clone();
+        }
+        // Start from the library curve so a trued scale is not applied twice
+        if let Some(curve) = &mut data.drag_curve {
+            if let Some(stored) = self.drag_curves.iter().find(|c| c.id == curve.id) {
+                *curve = stored.clone();
+            }
+        }
+        if let Some(truing) = truing {
+            truing.apply(data);
+            return;
+        }
+
+        let profile = self.selected_profile.and_then(|i| self.firearm_profiles.get(i));
+        let (Some(load), Some(profile)) = (&self.applied_load, profile) else {
+            return;
+        };
+        if let Some(adjustment) = load.barrel_adjustment(profile.barrel_length) {
+            data.velocity = adjustment.velocity();
+            self.barrel_adjustment = Some(adjustment);
+        }
     }
 
     fn add_photo(&mut self) {
@@ -1651,6 +3092,8 @@ impl BallisticsApp {
         self.current_screen = Screen::Login;
         self.calculation_history.clear();
         self.firearm_profiles.clear();
+        self.drag_curves.clear();
+        self.load_library.reset_custom_loads();
         self.current_calculation = CalculationData::default();
         self.trajectory_results = None;
         self.attached_images.clear();
@@ -1658,7 +3101,12 @@ impl BallisticsApp {
 
     fn load_user_data(&mut self) {
         self.firearm_profiles = self.storage.load_profiles();
+        self.drag_curves = self.storage.load_drag_curves();
         self.calculation_history = self.storage.load_calculations();
+        self.load_library.reset_custom_loads();
+        for load in self.storage.load_custom_loads() {
+            self.load_library.add_custom_load(load);
+        }
     }
 
     fn load_calculation_history(&mut self) {
@@ -1767,6 +3215,34 @@ impl BallisticsApp {
         }
     }
 
+    fn copy_wind_card_to_clipboard(&self, results: &TrajectoryResult) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let text = wind_card_text(results);
+            if let Ok(mut clipboard) = arboard::Clipboard::new() {
+                let _ = clipboard.set_text(text);
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = results;
+        }
+    }
+
+    fn copy_range_card_to_clipboard(&self, results: &TrajectoryResult) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let text = range_card_text(results);
+            if let Ok(mut clipboard) = arboard::Clipboard::new() {
+                let _ = clipboard.set_text(text);
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = results;
+        }
+    }
+
     fn print_results(&self) {
         // Stub
     }
@@ -1780,6 +3256,51 @@ impl BallisticsApp {
         }
     }
 
+    fn import_drag_curve(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("Drag Curve", &["csv", "json", "txt"])
+                .pick_file()
+            {
+                let name = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("Custom Curve")
+                    .to_string();
+
+                let text = match std::fs::read_to_string(&path) {
+                    Ok(text) => text,
+                    Err(e) => {
+                        self.error_message = Some(format!("Failed to read drag curve: {}", e));
+                        return;
+                    }
+                };
+
+                let parsed = match path.extension().and_then(|e| e.to_str()) {
+                    Some("json") => DragCurve::from_json(&name, &text),
+                    _ => DragCurve::from_csv(&name, &text),
+                };
+
+                match parsed {
+                    // Without a reference diameter the curve cannot be flown
+                    Ok(curve) if curve.diameter <= 0.0 => self.pending_drag_curve = Some(curve),
+                    Ok(curve) => self.add_drag_curve(curve),
+                    Err(e) => {
+                        self.error_message = Some(e.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_drag_curve(&mut self, curve: DragCurve) {
+        self.storage.save_drag_curves(std::slice::from_ref(&curve));
+        self.current_calculation.projectile_data.drag_curve = Some(curve.clone());
+        self.drag_curves.push(curve);
+        self.error_message = Some("Drag curve imported successfully!".to_string());
+    }
+
     fn import_profiles(&mut self) {
         // Stub
     }
@@ -1808,6 +3329,33 @@ impl BallisticsApp {
         self.calculation_history.retain(|c| c.id != id);
     }
 
+    fn save_trued_load(&mut self, result: &TruingResult) {
+        let data = &self.current_calculation.projectile_data;
+        let mut load = load_data::LoadData {
+            manufacturer: "Custom".to_string(),
+            name: format!("{} {}gr (trued)", data.caliber, data.mass),
+            caliber: data.caliber.clone(),
+            bullet_weight: data.mass,
+            velocity: result.velocity,
+            bc: result.bc,
+            drag_model: data.drag_model,
+            bc_segments: result.bc_segments.clone(),
+            powder_type: String::new(),
+            powder_charge: 0.0,
+            truing: None,
+            powder_sensitivity: data.powder.clone().map(|powder| PowderSensitivity {
+                powder_temperature: None,
+                ..powder
+            }),
+            test_barrel_length: None, // velocity was trued in this rifle
+            bullet_diameter: (data.bullet_diameter > 0.0).then_some(data.bullet_diameter),
+        };
+        load.truing = Some(result.record(data, Some(load.key()), Utc::now().to_rfc3339()));
+        self.storage.save_custom_load(&load);
+        self.load_library.add_custom_load(load);
+        self.error_message = Some("Trued load saved to the Custom library".to_string());
+    }
+
     fn add_custom_load(&mut self) {
         // Stub
     }
@@ -1870,6 +3418,8 @@ impl BallisticsApp {
         self.storage.clear_all();
         self.calculation_history.clear();
         self.firearm_profiles.clear();
+        self.drag_curves.clear();
+        self.load_library.reset_custom_loads();
         self.current_calculation = CalculationData::default();
         self.trajectory_results = None;
         self.attached_images.clear();
@@ -1878,6 +3428,74 @@ impl BallisticsApp {
 }
 
 
+/// Plain-text wind card: holds for each called wind speed at every range.
+fn wind_card_text(results: &TrajectoryResult) -> String {
+    let mut text = format!(
+        "WIND CARD (full value, hold into wind), constant {:.1}\n",
+        results.wind_constant
+    );
+    text.push_str(&format!("{:>6}", results.range_unit.abbreviation()));
+    for speed in WIND_BRACKET_SPEEDS {
+        text.push_str(&format!(" | {:^12}", format!("{} mph", speed)));
+    }
+    text.push_str(&format!("\n{:>6}", ""));
+    for _ in WIND_BRACKET_SPEEDS {
+        text.push_str(&format!(" | {:>5} {:>6}", "MOA", "MIL"));
+    }
+    text.push('\n');
+    for point in results.trajectory_points.iter().filter(|p| p.distance > 0.0) {
+        text.push_str(&format!("{:>6.0}", point.distance));
+        for hold in &point.wind_holds {
+            text.push_str(&format!(" | {:>5.1} {:>6.2}", hold.moa, hold.mil));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Plain-text range card: dial and windage for each range, with leads when a
+/// moving target is set.
+fn range_card_text(results: &TrajectoryResult) -> String {
+    let show_lead = results.trajectory_points.iter().any(|p| p.lead.is_some());
+    let mut text = format!("RANGE CARD, zero offset {:.2} MOA\n", results.zero_offset);
+    text.push_str(&format!(
+        "{:>6} | {:>7} {:>5} {:>6} | {:>7}",
+        results.range_unit.abbreviation(),
+        "Drop\"",
+        "MOA",
+        "MIL",
+        "Drift\""
+    ));
+    if show_lead {
+        text.push_str(&format!(" | {:>6} {:>5} {:>6} {:>5}", "Lead\"", "MOA", "MIL", "Len"));
+    }
+    if let Some(turret) = &results.turret {
+        text.push_str(&format!(" | {:>5} {:>5}", "Up", "Right"));
+        text.push_str(&format!(" ({} {} clicks)", turret.click_value, turret.unit));
+    }
+    text.push('\n');
+    for point in results.trajectory_points.iter().filter(|p| p.distance > 0.0) {
+        text.push_str(&format!(
+            "{:>6.0} | {:>7.1} {:>5.1} {:>6.2} | {:>7.1}",
+            point.distance, point.drop, point.moa_adjustment, point.mil_adjustment, point.drift
+        ));
+        if let Some(lead) = &point.lead {
+            text.push_str(&format!(
+                " | {:>6.1} {:>5.1} {:>6.2} {:>5.2}",
+                lead.inches, lead.moa, lead.mil, lead.lengths
+            ));
+        }
+        if let Some(clicks) = &point.clicks {
+            text.push_str(&format!(
+                " | {:>5} {:>5}",
+                clicks.elevation_rounded, clicks.windage_rounded
+            ));
+        }
+        text.push('\n');
+    }
+    text
+}
+
 // Settings struct
 #[derive(Clone, Serialize, Deserialize)]
 struct Settings {
@@ -2,7 +2,9 @@ use std::path::PathBuf;
 use rusqlite::{params, Connection};
 
 use crate::{AttachedImage, SavedCalculation};  // These are re-exported from lib.rs
+use crate::ballistics::DragCurve;
 use crate::firearm_profiles::FirearmProfile;
+use crate::load_data::LoadData;
 
 #[derive(Default)]
 pub struct LocalStorage {
@@ -63,6 +65,24 @@ impl LocalStorage {
                 [],
             )
             .unwrap();
+
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS drag_curves (
+                    id TEXT PRIMARY KEY,
+                    data TEXT NOT NULL
+                )",
+                [],
+            )
+            .unwrap();
+
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS custom_loads (
+                    key TEXT PRIMARY KEY,
+                    data TEXT NOT NULL
+                )",
+                [],
+            )
+            .unwrap();
         }
     }
 
@@ -196,6 +216,107 @@ impl LocalStorage {
         profiles
     }
 
+    pub fn save_drag_curves(&self, curves: &[DragCurve]) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(path) = &self.db_path {
+                let conn = Connection::open(path).unwrap();
+
+                for curve in curves {
+                    let data = serde_json::to_string(curve).unwrap();
+                    conn.execute(
+                        "INSERT OR REPLACE INTO drag_curves (id, data) VALUES (?1, ?2)",
+                        params![&curve.id, &data],
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    pub fn load_drag_curves(&self) -> Vec<DragCurve> {
+        let mut curves = Vec::new();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(path) = &self.db_path {
+                let conn = Connection::open(path).unwrap();
+                let mut stmt = conn.prepare("SELECT data FROM drag_curves").unwrap();
+
+                let rows = stmt
+                    .query_map([], |row| {
+                        let data: String = row.get(0)?;
+                        let c: DragCurve = serde_json::from_str(&data).unwrap();
+                        Ok(c)
+                    })
+                    .unwrap();
+
+                for r in rows {
+                    if let Ok(c) = r {
+                        curves.push(c);
+                    }
+                }
+            }
+        }
+
+        curves
+    }
+
+    pub fn delete_drag_curve(&self, id: &str) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(path) = &self.db_path {
+                let conn = Connection::open(path).unwrap();
+                conn.execute("DELETE FROM drag_curves WHERE id = ?1", params![id])
+                    .unwrap();
+            }
+        }
+    }
+
+    pub fn save_custom_load(&self, load: &LoadData) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(path) = &self.db_path {
+                let conn = Connection::open(path).unwrap();
+
+                let data = serde_json::to_string(load).unwrap();
+                conn.execute(
+                    "INSERT OR REPLACE INTO custom_loads (key, data) VALUES (?1, ?2)",
+                    params![&load.key(), &data],
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    pub fn load_custom_loads(&self) -> Vec<LoadData> {
+        let mut loads = Vec::new();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(path) = &self.db_path {
+                let conn = Connection::open(path).unwrap();
+                let mut stmt = conn.prepare("SELECT data FROM custom_loads").unwrap();
+
+                let rows = stmt
+                    .query_map([], |row| {
+                        let data: String = row.get(0)?;
+                        let l: LoadData = serde_json::from_str(&data).unwrap();
+                        Ok(l)
+                    })
+                    .unwrap();
+
+                for r in rows {
+                    if let Ok(l) = r {
+                        loads.push(l);
+                    }
+                }
+            }
+        }
+
+        loads
+    }
+
     pub fn delete_calculation(&self, id: &str) {
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -220,4 +341,54 @@ impl LocalStorage {
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ballistics::DragModel;
+
+    fn load(name: &str, velocity: f64) -> LoadData {
+        LoadData {
+            manufacturer: "Handload".to_string(),
+            name: name.to_string(),
+            caliber: ".308 Winchester".to_string(),
+            bullet_weight: 175.0,
+            velocity,
+            bc: 0.505,
+            drag_model: DragModel::G1,
+            bc_segments: Vec::new(),
+            powder_type: "Varget".to_string(),
+            powder_charge: 43.5,
+            truing: None,
+            powder_sensitivity: None,
+            test_barrel_length: None,
+            bullet_diameter: None,
+        }
+    }
+
+    #[test]
+    fn custom_loads_round_trip_and_replace_by_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = LocalStorage {
+            db_path: Some(dir.path().join("test.db")),
+        };
+        storage.init_db();
+
+        storage.save_custom_load(&load("SMK", 2600.0));
+        storage.save_custom_load(&load("ELD-M", 2650.0));
+        // Saving under the same key replaces the stored load
+        storage.save_custom_load(&load("SMK", 2585.0));
+
+        let mut loads = storage.load_custom_loads();
+        loads.sort_by_key(|l| l.key());
+        let saved: Vec<(String, f64)> = loads.iter().map(|l| (l.key(), l.velocity)).collect();
+        assert_eq!(
+            saved,
+            vec![
+                ("Handload / ELD-M".to_string(), 2650.0),
+                ("Handload / SMK".to_string(), 2585.0),
+            ]
+        );
+        assert_eq!(loads[1].powder_type, "Varget");
+    }
+}
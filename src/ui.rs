@@ -19,7 +19,7 @@ pub fn draw_trajectory_graph(ui: &mut egui::Ui, results: &TrajectoryResult) {
         }
         ui.separator();
         unsafe {
-            ui.label(format!("Range: 0-{:.0} yards", results.max_range * ZOOM_LEVEL as f64));
+            ui.label(format!("Range: 0-{:.0} {}", results.max_range * ZOOM_LEVEL as f64, results.range_unit));
         }
     });
     
@@ -136,6 +136,27 @@ pub fn draw_trajectory_graph(ui: &mut egui::Ui, results: &TrajectoryResult) {
         }
     }
     
+    // Mark where the bullet goes transonic and subsonic
+    for (range, label, color) in [
+        (results.transonic_range, "M1.2", egui::Color32::from_rgb(255, 200, 100)),
+        (results.subsonic_range, "M1.0", egui::Color32::from_rgb(255, 120, 100)),
+    ] {
+        if let Some(range) = range.filter(|&r| r > 0.0 && r <= max_display_distance) {
+            let x = plot_rect.left() + ((range / max_display_distance) as f32) * plot_rect.width();
+            painter.line_segment(
+                [egui::pos2(x, plot_rect.top()), egui::pos2(x, plot_rect.bottom())],
+                egui::Stroke::new(1.0, color),
+            );
+            painter.text(
+                egui::pos2(x + 3.0, plot_rect.top() + 2.0),
+                egui::Align2::LEFT_TOP,
+                label,
+                egui::FontId::proportional(10.0),
+                color,
+            );
+        }
+    }
+    
     // Convert trajectory points to screen coordinates (inverted to show drop)
     let screen_points: Vec<egui::Pos2> = visible_points
         .iter()
@@ -178,8 +199,8 @@ pub fn draw_trajectory_graph(ui: &mut egui::Ui, results: &TrajectoryResult) {
             if let Some(p) = visible_points.get(i) {
                 let tooltip_pos = *point + egui::vec2(10.0, -30.0);
                 let tooltip_text = format!(
-                    "{:.0} yds\nDrop: {:.1}\"\nVel: {:.0} fps",
-                    p.distance, p.drop, p.velocity
+                    "{:.0} {}\nDrop: {:.1}\"\nVel: {:.0} fps (M{:.2})",
+                    p.distance, results.range_unit.abbreviation(), p.drop, p.velocity, p.mach
                 );
                 
                 // Draw tooltip background
@@ -212,7 +233,7 @@ pub fn draw_trajectory_graph(ui: &mut egui::Ui, results: &TrajectoryResult) {
     painter.text(
         egui::pos2(rect.center().x, rect.bottom() - 5.0),
         egui::Align2::CENTER_BOTTOM,
-        "Distance (yards)",
+        format!("Distance ({})", results.range_unit),
         egui::FontId::proportional(12.0),
         text_color,
     );
@@ -238,7 +259,7 @@ pub fn draw_trajectory_graph(ui: &mut egui::Ui, results: &TrajectoryResult) {
     // Show key stats below the graph
     ui.separator();
     ui.horizontal(|ui| {
-        ui.label(format!("📏 Max Range: {} yards", results.max_range));
+        ui.label(format!("📏 Max Range: {} {}", results.max_range, results.range_unit));
         ui.separator();
         ui.label(format!("📉 Max Drop: {:.1} inches", 
             visible_points.iter().map(|p| p.drop.abs()).fold(0.0_f64, f64::max)));
@@ -250,7 +271,7 @@ pub fn draw_trajectory_graph(ui: &mut egui::Ui, results: &TrajectoryResult) {
         if let Some(hover_pos) = response.hover_pos() {
             let x_norm = ((hover_pos.x - plot_rect.left()) / plot_rect.width()).clamp(0.0, 1.0);
             let approx_distance = max_display_distance * (x_norm as f64);
-            ui.label(format!("📍 {:.0} yds", approx_distance));
+            ui.label(format!("📍 {:.0} {}", approx_distance, results.range_unit.abbreviation()));
         }
     });
 }
\ No newline at end of file